@@ -23,10 +23,46 @@ fn bench_neighbors(c: &mut Criterion) {
     });
 }
 
+fn bench_mesh_codes_in_radius(c: &mut Criterion) {
+    c.bench_function("mesh_codes_in_radius_50km_third", |b| {
+        let center = Coordinate::new(35.6812, 139.7671).unwrap();
+        b.iter(|| {
+            mesh_codes_in_radius(black_box(center), black_box(50000.0), MeshLevel::Third).count()
+        })
+    });
+}
+
+fn bench_haversine_distance(c: &mut Criterion) {
+    let fixed = Coordinate::new(35.6812, 139.7671).unwrap();
+    let candidates: Vec<Coordinate> = (0..1000)
+        .map(|i| Coordinate::new(35.0 + i as f64 * 0.001, 139.0 + i as f64 * 0.001).unwrap())
+        .collect();
+
+    c.bench_function("haversine_distance_1000", |b| {
+        b.iter(|| {
+            candidates
+                .iter()
+                .map(|&other| haversine_distance(black_box(fixed), other))
+                .fold(0.0, |acc, d| acc + d)
+        })
+    });
+    c.bench_function("haversine_distance_precomputed_1000", |b| {
+        b.iter(|| {
+            let trig = haversine_precompute(black_box(fixed));
+            candidates
+                .iter()
+                .map(|&other| haversine_distance_precomputed(fixed, trig, other))
+                .fold(0.0, |acc, d| acc + d)
+        })
+    });
+}
+
 criterion_group!(
     benches,
     bench_coord_to_mesh,
     bench_mesh_to_bounds,
-    bench_neighbors
+    bench_neighbors,
+    bench_mesh_codes_in_radius,
+    bench_haversine_distance
 );
 criterion_main!(benches);
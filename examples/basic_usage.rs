@@ -89,5 +89,5 @@ fn main() {
     // ========================================
     println!("\n7. 文字列からメッシュコードをパース:");
     let mesh = MeshCode::from_str("5339").unwrap();
-    println!("   パース結果: {} (レベル: {:?})", mesh, mesh.level());
+    println!("   パース結果: {} ({})", mesh, mesh.level());
 }
@@ -24,11 +24,7 @@ fn main() {
     // ========================================
     // 東京駅付近の3次メッシュ
     let third_mesh = MeshCode::from_str("53394611").unwrap();
-    println!(
-        "開始メッシュ: {} (レベル: {})",
-        third_mesh,
-        third_mesh.level() as u8
-    );
+    println!("開始メッシュ: {} ({})", third_mesh, third_mesh.level());
 
     // ========================================
     // 1. 階層を上る（細かいメッシュ → 粗いメッシュ）
@@ -94,7 +90,7 @@ fn main() {
     println!("\n3. レベル変換（一気に親レベルへ変換）:");
 
     let mesh = MeshCode::from_str("53394611").unwrap();
-    println!("   元のメッシュ: {} (レベル {})", mesh, mesh.level() as u8);
+    println!("   元のメッシュ: {} ({})", mesh, mesh.level());
 
     // 3次メッシュから2次メッシュへ直接変換
     if let Ok(second) = to_level(mesh, MeshLevel::Second) {
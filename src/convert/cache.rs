@@ -0,0 +1,284 @@
+use crate::convert::{coord_to_mesh, mesh_to_bounds};
+use crate::error::Result;
+use crate::types::{BoundingBox, Coordinate, MeshCode, MeshLevel};
+use crate::utils::math;
+use std::collections::{HashMap, VecDeque};
+
+/// キャッシュした結果を使う際にメッシュ境界から離れているべき距離（度）
+///
+/// 境界付近の座標は浮動小数点誤差により隣のメッシュに変換され得るため、
+/// キャッシュを使わずに毎回変換します。
+const BOUNDARY_MARGIN: f64 = 1e-9;
+
+/// レベルと、メッシュサイズ単位で丸めた緯度・経度
+type CacheKey = (MeshLevel, i64, i64);
+
+#[derive(Debug, Clone, Copy)]
+struct CacheEntry {
+    mesh: MeshCode,
+    bounds: BoundingBox,
+    last_used: u64,
+}
+
+/// 座標からメッシュコードへの変換結果を保持するLRUキャッシュ
+///
+/// 近接した座標を繰り返し変換するサービス向けに、直近の変換結果を
+/// 座標をメッシュサイズ単位で丸めたキーで保持します。キャッシュしたメッシュの
+/// 境界内（境界のごく近くを除く）にある座標はキャッシュから返すため、
+/// 結果は常に[`coord_to_mesh`]と一致します。容量を超えた場合は最も長く
+/// 使われていない結果を破棄します。参照・破棄はいずれも償却O(1)です。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mut cache = ConversionCache::new(64);
+/// let coord = Coordinate::new(35.6812, 139.7671).unwrap();
+///
+/// let mesh = cache.get_or_convert(coord, MeshLevel::Third).unwrap();
+/// assert_eq!(mesh, coord_to_mesh(coord, MeshLevel::Third).unwrap());
+///
+/// let nearby = Coordinate::new(35.6813, 139.7672).unwrap();
+/// assert_eq!(cache.get_or_convert(nearby, MeshLevel::Third).unwrap(), mesh);
+/// assert_eq!(cache.hits(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConversionCache {
+    capacity: usize,
+    entries: HashMap<CacheKey, CacheEntry>,
+    /// 参照順に並べた（参照時刻, キー）の列
+    ///
+    /// 参照のたびに末尾へ追加し、古い要素は削除しません。要素の時刻がエントリの
+    /// `last_used`と一致する場合だけ有効で、一致しない要素は後の参照で無効になったものです。
+    recency: VecDeque<(u64, CacheKey)>,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl ConversionCache {
+    /// 最大`capacity`件の変換結果を保持するキャッシュを作成する（最小1件）
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        ConversionCache {
+            capacity,
+            entries: HashMap::with_capacity(capacity),
+            recency: VecDeque::with_capacity(capacity * 2),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// キャッシュを利用して座標をメッシュコードに変換する
+    ///
+    /// # 引数
+    /// * `coord` - 変換する座標
+    /// * `level` - 目的のメッシュレベル
+    ///
+    /// # 戻り値
+    /// [`coord_to_mesh`]と同じ変換結果
+    pub fn get_or_convert(&mut self, coord: Coordinate, level: MeshLevel) -> Result<MeshCode> {
+        self.clock += 1;
+        let key = (
+            level,
+            math::floor(coord.lat() / level.lat_size_degrees()) as i64,
+            math::floor(coord.lon() / level.lon_size_degrees()) as i64,
+        );
+
+        if let Some(entry) = self.entries.get_mut(&key) {
+            if is_well_inside(&entry.bounds, coord) {
+                entry.last_used = self.clock;
+                let mesh = entry.mesh;
+                self.hits += 1;
+                self.record_use(key);
+                return Ok(mesh);
+            }
+        }
+
+        self.misses += 1;
+        let mesh = coord_to_mesh(coord, level)?;
+        let bounds = mesh_to_bounds(mesh);
+        if is_well_inside(&bounds, coord) {
+            if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+                self.evict_least_recently_used();
+            }
+            self.entries.insert(
+                key,
+                CacheEntry {
+                    mesh,
+                    bounds,
+                    last_used: self.clock,
+                },
+            );
+            self.record_use(key);
+        }
+        Ok(mesh)
+    }
+
+    /// キャッシュから結果を返した回数
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// キャッシュを使わずに変換した回数
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// 保持している変換結果の件数
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 保持している変換結果がないか
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 保持している変換結果と統計をすべて破棄する
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+        self.clock = 0;
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// 現在の時刻でキーを参照したことを記録する
+    ///
+    /// 無効な要素が有効な要素と同数を超えたら取り除くため、列の長さは容量の2倍程度に収まり、
+    /// 取り除く処理は参照1回あたり償却O(1)です。
+    fn record_use(&mut self, key: CacheKey) {
+        self.recency.push_back((self.clock, key));
+        if self.recency.len() > self.capacity * 2 {
+            let entries = &self.entries;
+            self.recency
+                .retain(|&(stamp, key)| is_current(entries, key, stamp));
+        }
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        while let Some((stamp, key)) = self.recency.pop_front() {
+            if is_current(&self.entries, key, stamp) {
+                self.entries.remove(&key);
+                return;
+            }
+        }
+    }
+}
+
+/// 参照順の要素が、キーの最後の参照を指しているか
+fn is_current(entries: &HashMap<CacheKey, CacheEntry>, key: CacheKey, stamp: u64) -> bool {
+    entries
+        .get(&key)
+        .is_some_and(|entry| entry.last_used == stamp)
+}
+
+/// 座標が境界の内側にあり、どの辺からも[`BOUNDARY_MARGIN`]以上離れているか
+fn is_well_inside(bounds: &BoundingBox, coord: Coordinate) -> bool {
+    coord.lat() > bounds.min_lat() + BOUNDARY_MARGIN
+        && coord.lat() < bounds.max_lat() - BOUNDARY_MARGIN
+        && coord.lon() > bounds.min_lon() + BOUNDARY_MARGIN
+        && coord.lon() < bounds.max_lon() - BOUNDARY_MARGIN
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_conversions_hit_cache() {
+        let mut cache = ConversionCache::new(16);
+        let coord = Coordinate::new(35.6812, 139.7671).unwrap();
+        let expected = coord_to_mesh(coord, MeshLevel::Third).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(
+                cache.get_or_convert(coord, MeshLevel::Third).unwrap(),
+                expected
+            );
+        }
+        assert_eq!(cache.misses(), 1);
+        assert_eq!(cache.hits(), 9);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_clustered_conversions_match_coord_to_mesh() {
+        let mut cache = ConversionCache::new(32);
+        let levels = [MeshLevel::Second, MeshLevel::Third, MeshLevel::FourthHalf];
+
+        let mut total = 0;
+        for i in 0..40 {
+            for j in 0..40 {
+                let coord =
+                    Coordinate::new(35.67 + i as f64 * 0.0005, 139.76 + j as f64 * 0.0005).unwrap();
+                for level in levels {
+                    total += 1;
+                    assert_eq!(
+                        cache.get_or_convert(coord, level).unwrap(),
+                        coord_to_mesh(coord, level).unwrap()
+                    );
+                }
+            }
+        }
+
+        assert_eq!(cache.hits() + cache.misses(), total);
+        assert!(cache.hits() as f64 / total as f64 > 0.8);
+        assert!(cache.len() <= 32);
+    }
+
+    #[test]
+    fn test_boundary_and_eviction() {
+        let mut cache = ConversionCache::new(1);
+
+        // メッシュ境界上の座標はキャッシュしない
+        let boundary = Coordinate::new(35.675, 139.7625).unwrap();
+        let mesh = cache.get_or_convert(boundary, MeshLevel::Third).unwrap();
+        assert_eq!(mesh, coord_to_mesh(boundary, MeshLevel::Third).unwrap());
+        assert!(cache.is_empty());
+
+        let a = Coordinate::new(35.6812, 139.7671).unwrap();
+        let b = Coordinate::new(35.71, 139.81).unwrap();
+        cache.get_or_convert(a, MeshLevel::Third).unwrap();
+        cache.get_or_convert(b, MeshLevel::Third).unwrap();
+        assert_eq!(cache.len(), 1);
+
+        // aは破棄されているため再変換になる
+        cache.get_or_convert(a, MeshLevel::Third).unwrap();
+        assert_eq!(cache.hits(), 0);
+        assert_eq!(cache.misses(), 4);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.misses(), 0);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = ConversionCache::new(2);
+        let a = Coordinate::new(35.6812, 139.7671).unwrap();
+        let b = Coordinate::new(35.71, 139.81).unwrap();
+        let c = Coordinate::new(34.7025, 135.4959).unwrap();
+
+        cache.get_or_convert(a, MeshLevel::Third).unwrap();
+        cache.get_or_convert(b, MeshLevel::Third).unwrap();
+        // aを何度も参照してもbより新しい扱いになり、参照順の列も容量の範囲に収まる
+        for _ in 0..100 {
+            cache.get_or_convert(a, MeshLevel::Third).unwrap();
+        }
+        assert!(cache.recency.len() <= 4);
+
+        cache.get_or_convert(c, MeshLevel::Third).unwrap();
+        assert_eq!(cache.len(), 2);
+
+        let misses = cache.misses();
+        cache.get_or_convert(a, MeshLevel::Third).unwrap();
+        cache.get_or_convert(c, MeshLevel::Third).unwrap();
+        assert_eq!(cache.misses(), misses);
+        cache.get_or_convert(b, MeshLevel::Third).unwrap();
+        assert_eq!(cache.misses(), misses + 1);
+    }
+}
@@ -1,12 +1,17 @@
-use crate::error::Result;
-use crate::types::{Coordinate, MeshCode, MeshLevel};
+use crate::convert::mesh_to_center;
+use crate::error::{MeshCodeError, Result};
+use crate::types::{Coordinate, MeshCode, MeshLevel, JAPAN_BOUNDS};
+use crate::utils::distance::EARTH_RADIUS_METERS;
 use crate::utils::math;
-use alloc::format;
 
 /// 地理座標からメッシュコードに変換する
 ///
 /// JIS X 0410に基づいて、指定された座標を含むメッシュコードを計算します。
 ///
+/// メッシュコードで表せるのは北緯0度以上66度40分未満、東経100度以上180度以下の
+/// 座標です。[`Coordinate::new_unchecked`]で作成したこの範囲外の座標は
+/// [`crate::MeshCodeError::OutOfRange`]になります。
+///
 /// # 引数
 /// * `coord` - 変換する座標
 /// * `level` - 目的のメッシュレベル
@@ -24,109 +29,406 @@ use alloc::format;
 /// println!("メッシュコード: {}", mesh);
 /// ```
 pub fn coord_to_mesh(coord: Coordinate, level: MeshLevel) -> Result<MeshCode> {
-    let lat = coord.lat();
-    let lon = coord.lon();
+    MeshPrefixCache::new().convert(coord, level)
+}
 
-    let first_code = calc_first_mesh(lat, lon);
+/// 指定レベルで変換できない場合に、より粗いレベルへ段階的に切り替えて変換する
+///
+/// まず`desired_level`で変換し、メッシュコードの検証に失敗した場合は
+/// [`MeshLevel::parent`]をたどって粗いレベルで変換を試みます。
+/// 日本の範囲の端など、細かいレベルの桁が規則外になる座標でも、
+/// 表現できる最も細かいメッシュを得られます。
+///
+/// # 引数
+/// * `coord` - 変換する座標
+/// * `desired_level` - 希望するメッシュレベル
+///
+/// # 戻り値
+/// （メッシュコード、実際に得られたレベル）のタプル。
+/// 1次メッシュでも変換できない場合は1次メッシュでのエラー
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let coord = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let (mesh, level) = coord_to_mesh_best(coord, MeshLevel::Fifth).unwrap();
+/// assert_eq!(level, MeshLevel::Fifth);
+/// assert_eq!(mesh.level(), level);
+/// ```
+pub fn coord_to_mesh_best(
+    coord: Coordinate,
+    desired_level: MeshLevel,
+) -> Result<(MeshCode, MeshLevel)> {
+    let mut cache = MeshPrefixCache::new();
+    convert_best(desired_level, |level| cache.convert(coord, level))
+}
 
-    match level {
-        MeshLevel::First => MeshCode::new(level, first_code),
-        MeshLevel::Second => {
-            let second_code = calc_second_mesh(lat, lon, first_code);
-            MeshCode::new(level, second_code)
+/// `convert`が成功するまでレベルを粗くしながら変換を試みる
+fn convert_best<F>(desired_level: MeshLevel, mut convert: F) -> Result<(MeshCode, MeshLevel)>
+where
+    F: FnMut(MeshLevel) -> Result<MeshCode>,
+{
+    let mut level = desired_level;
+    loop {
+        match convert(level) {
+            Ok(mesh) => return Ok((mesh, level)),
+            Err(e) => match level.parent() {
+                Some(parent) => level = parent,
+                None => return Err(e),
+            },
         }
-        MeshLevel::Third => {
-            let second_code = calc_second_mesh(lat, lon, first_code);
-            let third_code = calc_third_mesh(lat, lon, second_code);
-            MeshCode::new(level, third_code)
+    }
+}
+
+/// メッシュ境界上の座標を内側へずらしてからメッシュコードに変換する
+///
+/// [`mesh_to_bounds`](crate::mesh_to_bounds)で得た南西端のように、メッシュ境界の
+/// 線上にある座標は浮動小数点誤差で南側・西側の隣接メッシュに変換されることがあります。
+/// この関数は、緯度・経度それぞれについて最も近い境界線との差が`epsilon_deg`以下の
+/// 場合に、座標を境界線から北側・東側へ`epsilon_deg`だけ離れた位置へ移してから
+/// 変換します。これにより、境界線上の座標はその線を南端・西端とするメッシュに
+/// 安定して変換されます。
+///
+/// その代わり、境界線の南側・西側`epsilon_deg`以内にある座標も北側・東側の
+/// メッシュになります。`epsilon_deg`はメッシュの大きさより十分小さい値
+/// （例: `1e-9`度、約0.1mm）を指定してください。0以下の場合は[`coord_to_mesh`]と
+/// 同じ結果になります。
+///
+/// # 引数
+/// * `coord` - 変換する座標
+/// * `level` - 目的のメッシュレベル
+/// * `epsilon_deg` - 境界線上とみなす許容誤差（度）
+///
+/// # 戻り値
+/// 計算されたメッシュコード
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let sw = mesh_to_bounds(mesh).south_west();
+/// assert_eq!(coord_to_mesh_tolerant(sw, MeshLevel::Third, 1e-9).unwrap(), mesh);
+/// ```
+pub fn coord_to_mesh_tolerant(
+    coord: Coordinate,
+    level: MeshLevel,
+    epsilon_deg: f64,
+) -> Result<MeshCode> {
+    if epsilon_deg <= 0.0 {
+        return coord_to_mesh(coord, level);
+    }
+
+    let nudge = |value: f64, origin: f64, size: f64| {
+        let line = origin + math::round((value - origin) / size) * size;
+        if (value - line).abs() <= epsilon_deg {
+            line + epsilon_deg
+        } else {
+            value
         }
-        MeshLevel::FourthHalf => {
-            let second_code = calc_second_mesh(lat, lon, first_code);
-            let third_code = calc_third_mesh(lat, lon, second_code);
-            let fourth_code = calc_fourth_half_mesh(lat, lon, third_code);
-            MeshCode::new(level, fourth_code)
+    };
+    let lat = nudge(coord.lat(), 0.0, level.lat_size_degrees());
+    let lon = nudge(coord.lon(), 100.0, level.lon_size_degrees());
+
+    coord_to_mesh(Coordinate::new_unchecked(lat, lon), level)
+}
+
+/// [`postal_centroid_to_mesh`]で日本の範囲の境界へ寄せる範囲外の許容量（度）
+const POSTAL_CLAMP_TOLERANCE_DEG: f64 = 0.1;
+
+/// 郵便番号の代表点などの緯度・経度をメッシュコードに変換する
+///
+/// 外部の郵便番号データの代表点（緯度・経度）を変換するETL処理向けの補助関数です。
+/// 代表点はジオコーディングの誤差で日本の範囲（北緯20〜46度、東経122〜154度）を
+/// わずかに外れることがあるため、範囲外へのはみ出しが0.1度（約11km）以内であれば
+/// 緯度・経度をそれぞれ範囲の境界に寄せてから変換します。寄せた場合の結果は
+/// 範囲の端のメッシュになり、元の位置を含むとは限りません。
+///
+/// はみ出しが0.1度を超える場合や、緯度・経度が有限の数値でない場合は
+/// [`MeshCodeError::OutOfRange`]を返します。
+///
+/// # 引数
+/// * `lat` - 代表点の緯度（度）
+/// * `lon` - 代表点の経度（度）
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// 計算されたメッシュコード
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// // 〒100-0005（東京都千代田区丸の内）の代表点
+/// let mesh = postal_centroid_to_mesh(35.6812, 139.7671, MeshLevel::Third).unwrap();
+/// assert_eq!(mesh.as_string(), "53394611");
+///
+/// // わずかに範囲外の代表点は境界に寄せて変換する
+/// assert!(postal_centroid_to_mesh(45.55, 154.03, MeshLevel::Third).is_ok());
+/// assert!(postal_centroid_to_mesh(47.0, 139.0, MeshLevel::Third).is_err());
+/// ```
+pub fn postal_centroid_to_mesh(lat: f64, lon: f64, level: MeshLevel) -> Result<MeshCode> {
+    if !lat.is_finite() || !lon.is_finite() {
+        return Err(MeshCodeError::OutOfRange);
+    }
+
+    let clamp = |value: f64, min: f64, max: f64| {
+        if value < min - POSTAL_CLAMP_TOLERANCE_DEG || value > max + POSTAL_CLAMP_TOLERANCE_DEG {
+            None
+        } else {
+            Some(value.clamp(min, max))
         }
-        MeshLevel::FourthQuarter => {
-            let second_code = calc_second_mesh(lat, lon, first_code);
-            let third_code = calc_third_mesh(lat, lon, second_code);
-            let fourth_code = calc_fourth_quarter_mesh(lat, lon, third_code);
-            MeshCode::new(level, fourth_code)
+    };
+    let lat = clamp(lat, JAPAN_BOUNDS.min_lat(), JAPAN_BOUNDS.max_lat())
+        .ok_or(MeshCodeError::OutOfRange)?;
+    let lon = clamp(lon, JAPAN_BOUNDS.min_lon(), JAPAN_BOUNDS.max_lon())
+        .ok_or(MeshCodeError::OutOfRange)?;
+
+    let coord = Coordinate::new(lat, lon).map_err(|_| MeshCodeError::OutOfRange)?;
+    coord_to_mesh(coord, level)
+}
+
+/// 座標を、その座標を含むメッシュの中心座標に丸める
+///
+/// 指定レベルのメッシュに変換し、そのメッシュの中心座標を返します。
+/// クラスタリングなどで座標をメッシュ単位に量子化する用途に使います。
+///
+/// # 引数
+/// * `coord` - 丸める座標
+/// * `level` - 丸めに使うメッシュレベル
+///
+/// # 戻り値
+/// メッシュの中心座標
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let coord = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let center = snap_to_mesh_center(coord, MeshLevel::Third).unwrap();
+/// assert_eq!(center, mesh_to_center(coord_to_mesh(coord, MeshLevel::Third).unwrap()));
+/// ```
+pub fn snap_to_mesh_center(coord: Coordinate, level: MeshLevel) -> Result<Coordinate> {
+    coord_to_mesh(coord, level).map(mesh_to_center)
+}
+
+/// 起点から方位角と距離だけ移動した地点を含むメッシュコードを求める
+///
+/// 大円に沿って移動した地点を順算公式で求め、その地点を変換します。
+/// 移動先が日本の範囲（北緯20〜46度、東経122〜154度）外の場合は
+/// [`MeshCodeError::OutOfRange`]を返します。
+///
+/// # 引数
+/// * `origin` - 起点の座標
+/// * `bearing_deg` - 方位角（度、北=0、時計回り）
+/// * `distance_m` - 移動距離（メートル）
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// 移動先の地点を含むメッシュコード
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// // 東京駅から北東へ2km
+/// let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let mesh = mesh_at_offset(tokyo, 45.0, 2000.0, MeshLevel::Third).unwrap();
+/// assert_ne!(mesh, coord_to_mesh(tokyo, MeshLevel::Third).unwrap());
+/// ```
+pub fn mesh_at_offset(
+    origin: Coordinate,
+    bearing_deg: f64,
+    distance_m: f64,
+    level: MeshLevel,
+) -> Result<MeshCode> {
+    let delta = distance_m / EARTH_RADIUS_METERS;
+    let theta = bearing_deg.to_radians();
+    let lat1 = origin.lat_rad();
+    let lon1 = origin.lon_rad();
+
+    let sin_lat2 =
+        math::sin(lat1) * math::cos(delta) + math::cos(lat1) * math::sin(delta) * math::cos(theta);
+    let lat2 = math::asin(sin_lat2.clamp(-1.0, 1.0));
+    let lon2 = lon1
+        + math::atan2(
+            math::sin(theta) * math::sin(delta) * math::cos(lat1),
+            math::cos(delta) - math::sin(lat1) * sin_lat2,
+        );
+
+    let destination = Coordinate::new(lat2.to_degrees(), lon2.to_degrees())
+        .map_err(|_| MeshCodeError::OutOfRange)?;
+    coord_to_mesh(destination, level)
+}
+
+/// メッシュのコード値と南西端の座標
+#[derive(Debug, Clone, Copy)]
+struct MeshOrigin {
+    code: u64,
+    lat: f64,
+    lon: f64,
+}
+
+/// 1次・2次メッシュの接頭辞をキャッシュしながら座標を変換する
+///
+/// 範囲検索のように近接した座標を連続して変換する場合、1次・2次メッシュは
+/// ほとんど変わりません。直前に変換した1次・2次メッシュの南西端を保持しておき、
+/// 同じ親メッシュ内の座標では再計算を省いて3次以降の桁だけを組み立てます。
+/// 親メッシュの境界を越えたときのみ再計算するため、結果は毎回
+/// [`coord_to_mesh`]を呼んだ場合と常に一致します。
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MeshPrefixCache {
+    first: Option<MeshOrigin>,
+    second: Option<MeshOrigin>,
+}
+
+impl MeshPrefixCache {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// キャッシュを利用して座標をメッシュコードに変換する
+    pub(crate) fn convert(&mut self, coord: Coordinate, level: MeshLevel) -> Result<MeshCode> {
+        let origin = self.origin(coord, level)?;
+        MeshCode::new(level, origin.code)
+    }
+
+    /// キャッシュを利用して座標をメッシュコードに変換し、メッシュの中心座標と組にして返す
+    ///
+    /// 中心座標は変換の途中で求めた南西端から計算するため、メッシュコードを
+    /// 再度解析する必要がありません。南西端は[`crate::mesh_to_bounds`]と同じ式で
+    /// 求めるため、結果は[`crate::mesh_to_center`]と一致します。
+    pub(crate) fn convert_with_center(
+        &mut self,
+        coord: Coordinate,
+        level: MeshLevel,
+    ) -> Result<(MeshCode, Coordinate)> {
+        let origin = self.origin(coord, level)?;
+        let mesh = MeshCode::new(level, origin.code)?;
+        let north = origin.lat + level.lat_size_degrees();
+        let east = origin.lon + level.lon_size_degrees();
+        let center =
+            Coordinate::new_unchecked((origin.lat + north) / 2.0, (origin.lon + east) / 2.0);
+        Ok((mesh, center))
+    }
+
+    /// 座標を含む指定レベルのメッシュのコード値と南西端を求める
+    fn origin(&mut self, coord: Coordinate, level: MeshLevel) -> Result<MeshOrigin> {
+        let lat = coord.lat();
+        let lon = coord.lon();
+
+        let first = self.first_mesh(lat, lon)?;
+        if level == MeshLevel::First {
+            return Ok(first);
         }
-        MeshLevel::FourthEighth => {
-            let second_code = calc_second_mesh(lat, lon, first_code);
-            let third_code = calc_third_mesh(lat, lon, second_code);
-            let fourth_code = calc_fourth_eighth_mesh(lat, lon, third_code);
-            MeshCode::new(level, fourth_code)
+
+        let second = self.second_mesh(lat, lon, first);
+        if level == MeshLevel::Second {
+            return Ok(second);
         }
-        MeshLevel::Fifth => {
-            let second_code = calc_second_mesh(lat, lon, first_code);
-            let third_code = calc_third_mesh(lat, lon, second_code);
-            let fifth_code = calc_fifth_mesh(lat, lon, third_code);
-            MeshCode::new(level, fifth_code)
+
+        let third = calc_third_mesh(lat, lon, second);
+        Ok(match level {
+            MeshLevel::FourthHalf => calc_fourth_half_mesh(lat, lon, third),
+            MeshLevel::FourthQuarter => calc_fourth_quarter_mesh(lat, lon, third),
+            MeshLevel::FourthEighth => calc_fourth_eighth_mesh(lat, lon, third),
+            MeshLevel::Fifth => calc_fifth_mesh(lat, lon, third),
+            _ => third,
+        })
+    }
+
+    fn first_mesh(&mut self, lat: f64, lon: f64) -> Result<MeshOrigin> {
+        let (code, lat_times_1_5, lon_minus_100) = calc_first_mesh(lat, lon)?;
+        let origin = match self.first {
+            Some(cached) if cached.code == code => cached,
+            _ => {
+                let origin = MeshOrigin {
+                    code,
+                    lat: lat_times_1_5 as f64 / 1.5,
+                    lon: lon_minus_100 as f64 + 100.0,
+                };
+                self.first = Some(origin);
+                self.second = None;
+                origin
+            }
+        };
+        Ok(origin)
+    }
+
+    fn second_mesh(&mut self, lat: f64, lon: f64, first: MeshOrigin) -> MeshOrigin {
+        let (t, u) = calc_second_digits(lat - first.lat, lon - first.lon);
+        let code = first.code * 100 + (t * 10 + u) as u64;
+        match self.second {
+            Some(cached) if cached.code == code => cached,
+            _ => {
+                let origin = MeshOrigin {
+                    code,
+                    lat: first.lat + t as f64 * (40.0 / 60.0) / 8.0,
+                    lon: first.lon + u as f64 / 8.0,
+                };
+                self.second = Some(origin);
+                origin
+            }
         }
     }
 }
 
-fn calc_first_mesh(lat: f64, lon: f64) -> u64 {
-    let lat_times_1_5 = math::floor(lat * 1.5) as i32;
+/// 1次メッシュのコード値と、南西端の算出に使う整数（緯度×1.5、経度−100）を返す
+///
+/// 1次メッシュコードは緯度×1.5と経度−100をそれぞれ2桁で表すため、メッシュコードに
+/// 変換できるのは北緯0度以上66度40分未満、東経100度以上200度未満（座標として
+/// 有効な範囲では180度以下）です。この範囲外では[`MeshCodeError::OutOfRange`]を返します。
+fn calc_first_mesh(lat: f64, lon: f64) -> Result<(u64, i32, i32)> {
+    let lat_times_1_5 = math::floor(lat * 1.5);
+    let lon_minus_100 = math::floor(lon - 100.0);
+    if !(0.0..100.0).contains(&lat_times_1_5) || !(0.0..100.0).contains(&lon_minus_100) {
+        return Err(MeshCodeError::OutOfRange);
+    }
+    let lat_times_1_5 = lat_times_1_5 as i32;
+    let lon_minus_100 = lon_minus_100 as i32;
+
     let p = lat_times_1_5 / 10;
     let q = lat_times_1_5 % 10;
-
-    let lon_minus_100 = math::floor(lon - 100.0) as i32;
     let r = lon_minus_100 / 10;
     let s = lon_minus_100 % 10;
 
-    (p * 1000 + q * 100 + r * 10 + s) as u64
+    let code = (p * 1000 + q * 100 + r * 10 + s) as u64;
+    Ok((code, lat_times_1_5, lon_minus_100))
 }
 
-fn calc_second_mesh(lat: f64, lon: f64, first_code: u64) -> u64 {
-    let first_str = format!("{first_code:04}");
-    let p = first_str[0..1].parse::<f64>().unwrap();
-    let q = first_str[1..2].parse::<f64>().unwrap();
-    let r = first_str[2..3].parse::<f64>().unwrap();
-    let s = first_str[3..4].parse::<f64>().unwrap();
-
-    let first_lat = (p * 10.0 + q) / 1.5;
-    let first_lon = r * 10.0 + s + 100.0;
-
-    let lat_in_mesh = lat - first_lat;
-    let lon_in_mesh = lon - first_lon;
-
+fn calc_second_digits(lat_in_mesh: f64, lon_in_mesh: f64) -> (i32, i32) {
     // 浮動小数点誤差でメッシュ境界を越えないよう0〜7に制限する
     let t = (math::floor(lat_in_mesh / (40.0 / 60.0) * 8.0) as i32).clamp(0, 7);
     let u = (math::floor(lon_in_mesh * 8.0) as i32).clamp(0, 7);
-
-    first_code * 100 + (t * 10 + u) as u64
+    (t, u)
 }
 
-fn calc_third_mesh(lat: f64, lon: f64, second_code: u64) -> u64 {
-    let second_str = format!("{second_code:06}");
-    let first_str = &second_str[0..4];
-    let p = first_str[0..1].parse::<f64>().unwrap();
-    let q = first_str[1..2].parse::<f64>().unwrap();
-    let r = first_str[2..3].parse::<f64>().unwrap();
-    let s = first_str[3..4].parse::<f64>().unwrap();
-    let t = second_str[4..5].parse::<f64>().unwrap();
-    let u = second_str[5..6].parse::<f64>().unwrap();
-
-    let first_lat = (p * 10.0 + q) / 1.5;
-    let first_lon = r * 10.0 + s + 100.0;
-    let second_lat = first_lat + t * (40.0 / 60.0) / 8.0;
-    let second_lon = first_lon + u / 8.0;
-
-    let lat_in_mesh = lat - second_lat;
-    let lon_in_mesh = lon - second_lon;
+fn calc_third_mesh(lat: f64, lon: f64, second: MeshOrigin) -> MeshOrigin {
+    let lat_in_mesh = lat - second.lat;
+    let lon_in_mesh = lon - second.lon;
 
     // 浮動小数点誤差でメッシュ境界を越えないよう0〜9に制限する
     let v = (math::floor(lat_in_mesh / (5.0 / 60.0) * 10.0) as i32).clamp(0, 9);
     let w = (math::floor(lon_in_mesh / (7.5 / 60.0) * 10.0) as i32).clamp(0, 9);
 
-    second_code * 100 + (v * 10 + w) as u64
+    MeshOrigin {
+        code: second.code * 100 + (v * 10 + w) as u64,
+        lat: second.lat + v as f64 * (5.0 / 60.0) / 10.0,
+        lon: second.lon + w as f64 * (7.5 / 60.0) / 10.0,
+    }
 }
 
 /// 分割地域メッシュの番号を計算する（JIS X 0410）
 ///
 /// メッシュを南北・東西に2等分し、南西=1、南東=2、北西=3、北東=4を割り当てます。
+/// 番号は半分の位置との比較だけで決まるため、浮動小数点誤差で座標がメッシュの
+/// 境界をわずかに越えていても常に1〜4の範囲に収まります。
 /// 戻り値は（番号、分割後メッシュ内の残余緯度、残余経度）です。
 fn subdivision_index(
     lat_in_mesh: f64,
@@ -157,33 +459,44 @@ fn subdivision_index(
 const THIRD_LAT_SIZE: f64 = 30.0 / 3600.0;
 const THIRD_LON_SIZE: f64 = 45.0 / 3600.0;
 
-fn calc_fourth_half_mesh(lat: f64, lon: f64, third_code: u64) -> u64 {
-    let third_str = format!("{third_code:08}");
-    let lat_in_mesh = lat - extract_lat_from_third(&third_str);
-    let lon_in_mesh = lon - extract_lon_from_third(&third_str);
+/// 親メッシュに分割番号を1桁付け加えた子メッシュを返す
+///
+/// 南西端は親の南西端に、番号に対応する分割後のメッシュサイズ分のオフセットを加えて求めます
+/// （[`crate::mesh_to_bounds`]と同じ計算）。`divisor`は3次メッシュに対する分割後のサイズの比です。
+fn subdivide(parent: MeshOrigin, index: u64, divisor: f64) -> MeshOrigin {
+    let lat_units = ((index - 1) / 2) as f64;
+    let lon_units = ((index - 1) % 2) as f64;
+    MeshOrigin {
+        code: parent.code * 10 + index,
+        lat: parent.lat + lat_units * (THIRD_LAT_SIZE / divisor),
+        lon: parent.lon + lon_units * (THIRD_LON_SIZE / divisor),
+    }
+}
+
+fn calc_fourth_half_mesh(lat: f64, lon: f64, third: MeshOrigin) -> MeshOrigin {
+    let lat_in_mesh = lat - third.lat;
+    let lon_in_mesh = lon - third.lon;
 
     let (index, _, _) = subdivision_index(lat_in_mesh, lon_in_mesh, THIRD_LAT_SIZE, THIRD_LON_SIZE);
 
-    third_code * 10 + index
+    subdivide(third, index, 2.0)
 }
 
-fn calc_fourth_quarter_mesh(lat: f64, lon: f64, third_code: u64) -> u64 {
-    let third_str = format!("{third_code:08}");
-    let lat_in_mesh = lat - extract_lat_from_third(&third_str);
-    let lon_in_mesh = lon - extract_lon_from_third(&third_str);
+fn calc_fourth_quarter_mesh(lat: f64, lon: f64, third: MeshOrigin) -> MeshOrigin {
+    let lat_in_mesh = lat - third.lat;
+    let lon_in_mesh = lon - third.lon;
 
     let (half, lat_rem, lon_rem) =
         subdivision_index(lat_in_mesh, lon_in_mesh, THIRD_LAT_SIZE, THIRD_LON_SIZE);
     let (quarter, _, _) =
         subdivision_index(lat_rem, lon_rem, THIRD_LAT_SIZE / 2.0, THIRD_LON_SIZE / 2.0);
 
-    third_code * 100 + half * 10 + quarter
+    subdivide(subdivide(third, half, 2.0), quarter, 4.0)
 }
 
-fn calc_fourth_eighth_mesh(lat: f64, lon: f64, third_code: u64) -> u64 {
-    let third_str = format!("{third_code:08}");
-    let lat_in_mesh = lat - extract_lat_from_third(&third_str);
-    let lon_in_mesh = lon - extract_lon_from_third(&third_str);
+fn calc_fourth_eighth_mesh(lat: f64, lon: f64, third: MeshOrigin) -> MeshOrigin {
+    let lat_in_mesh = lat - third.lat;
+    let lon_in_mesh = lon - third.lon;
 
     let (half, lat_rem, lon_rem) =
         subdivision_index(lat_in_mesh, lon_in_mesh, THIRD_LAT_SIZE, THIRD_LON_SIZE);
@@ -192,48 +505,167 @@ fn calc_fourth_eighth_mesh(lat: f64, lon: f64, third_code: u64) -> u64 {
     let (eighth, _, _) =
         subdivision_index(lat_rem, lon_rem, THIRD_LAT_SIZE / 4.0, THIRD_LON_SIZE / 4.0);
 
-    third_code * 1000 + half * 100 + quarter * 10 + eighth
+    subdivide(
+        subdivide(subdivide(third, half, 2.0), quarter, 4.0),
+        eighth,
+        8.0,
+    )
 }
 
-fn calc_fifth_mesh(lat: f64, lon: f64, third_code: u64) -> u64 {
-    let third_str = format!("{third_code:08}");
-    let lat_in_mesh = lat - extract_lat_from_third(&third_str);
-    let lon_in_mesh = lon - extract_lon_from_third(&third_str);
+fn calc_fifth_mesh(lat: f64, lon: f64, third: MeshOrigin) -> MeshOrigin {
+    let lat_in_mesh = lat - third.lat;
+    let lon_in_mesh = lon - third.lon;
 
     // 3次メッシュを緯度・経度方向に10等分し、南から北へ0〜9、西から東へ0〜9を割り当てる
     let lat_no = (math::floor(lat_in_mesh / (3.0 / 3600.0)) as i32).clamp(0, 9);
     let lon_no = (math::floor(lon_in_mesh / (4.5 / 3600.0)) as i32).clamp(0, 9);
 
-    third_code * 100 + (lat_no * 10 + lon_no) as u64
+    MeshOrigin {
+        code: third.code * 100 + (lat_no * 10 + lon_no) as u64,
+        lat: third.lat + lat_no as f64 * (3.0 / 3600.0),
+        lon: third.lon + lon_no as f64 * (4.5 / 3600.0),
+    }
 }
 
-fn extract_lat_from_third(third_str: &str) -> f64 {
-    let p = third_str[0..1].parse::<f64>().unwrap();
-    let q = third_str[1..2].parse::<f64>().unwrap();
-    let t = third_str[4..5].parse::<f64>().unwrap();
-    let v = third_str[6..7].parse::<f64>().unwrap();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::mesh_to_bounds;
 
-    let first_lat = (p * 10.0 + q) / 1.5;
-    let second_lat = first_lat + t * (40.0 / 60.0) / 8.0;
+    #[test]
+    fn test_postal_centroid_to_mesh_near_boundary() {
+        // 東端をわずかに越えた代表点は東端のメッシュになる
+        let mesh = postal_centroid_to_mesh(24.28, 154.05, MeshLevel::Third).unwrap();
+        let edge = Coordinate::new(24.28, 154.0).unwrap();
+        assert_eq!(mesh, coord_to_mesh(edge, MeshLevel::Third).unwrap());
+
+        // 範囲内の代表点は通常の変換と同じ
+        let inside = postal_centroid_to_mesh(24.28, 153.98, MeshLevel::Third).unwrap();
+        let coord = Coordinate::new(24.28, 153.98).unwrap();
+        assert_eq!(inside, coord_to_mesh(coord, MeshLevel::Third).unwrap());
+
+        let south = postal_centroid_to_mesh(19.95, 136.08, MeshLevel::Second).unwrap();
+        let edge = Coordinate::new(20.0, 136.08).unwrap();
+        assert_eq!(south, coord_to_mesh(edge, MeshLevel::Second).unwrap());
+
+        for (lat, lon) in [
+            (19.8, 136.0),
+            (35.0, 154.2),
+            (f64::NAN, 139.0),
+            (35.0, f64::INFINITY),
+        ] {
+            assert_eq!(
+                postal_centroid_to_mesh(lat, lon, MeshLevel::Third),
+                Err(MeshCodeError::OutOfRange)
+            );
+        }
+    }
 
-    second_lat + v * (5.0 / 60.0) / 10.0
-}
+    #[test]
+    fn test_coord_to_mesh_tolerant_sw_corner() {
+        let codes = [
+            "5339",
+            "533946",
+            "53394611",
+            "53394699",
+            "533946114",
+            "5339461142",
+            "53394611423",
+            "36225777",
+        ];
+        for code in codes {
+            let mesh = MeshCode::from_str(code).unwrap();
+            let sw = mesh_to_bounds(mesh).south_west();
+            assert_eq!(
+                coord_to_mesh_tolerant(sw, mesh.level(), 1e-9).unwrap(),
+                mesh,
+                "{code}"
+            );
+        }
 
-fn extract_lon_from_third(third_str: &str) -> f64 {
-    let r = third_str[2..3].parse::<f64>().unwrap();
-    let s = third_str[3..4].parse::<f64>().unwrap();
-    let u = third_str[5..6].parse::<f64>().unwrap();
-    let w = third_str[7..8].parse::<f64>().unwrap();
+        let fifth = MeshCode::parse("5339461197", Some(MeshLevel::Fifth)).unwrap();
+        let sw = mesh_to_bounds(fifth).south_west();
+        assert_eq!(
+            coord_to_mesh_tolerant(sw, MeshLevel::Fifth, 1e-9).unwrap(),
+            fifth
+        );
+
+        // 境界から離れた座標は通常の変換と同じ
+        let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+        assert_eq!(
+            coord_to_mesh_tolerant(tokyo, MeshLevel::Third, 1e-9).unwrap(),
+            coord_to_mesh(tokyo, MeshLevel::Third).unwrap()
+        );
+    }
 
-    let first_lon = r * 10.0 + s + 100.0;
-    let second_lon = first_lon + u / 8.0;
+    #[test]
+    fn test_longitude_domain() {
+        // 日本の範囲の西端・東端
+        let west = Coordinate::new(35.0, 122.0).unwrap();
+        assert_eq!(
+            coord_to_mesh(west, MeshLevel::First).unwrap().as_string(),
+            "5222"
+        );
+        let east = Coordinate::new(35.0, 154.0).unwrap();
+        assert_eq!(
+            coord_to_mesh(east, MeshLevel::First).unwrap().as_string(),
+            "5254"
+        );
+
+        // 東経180度は2桁の経度コードで表せる
+        let date_line = Coordinate::new_unchecked(35.0, 180.0);
+        assert_eq!(
+            coord_to_mesh(date_line, MeshLevel::First)
+                .unwrap()
+                .as_string(),
+            "5280"
+        );
+
+        // 東経100度未満・北緯0度未満・北緯66度40分以上はメッシュコードにできない
+        for (lat, lon) in [(35.0, 99.5), (35.0, -120.0), (-1.0, 139.0), (66.7, 139.0)] {
+            let coord = Coordinate::new_unchecked(lat, lon);
+            for level in [MeshLevel::First, MeshLevel::Third, MeshLevel::Fifth] {
+                assert_eq!(coord_to_mesh(coord, level), Err(MeshCodeError::OutOfRange));
+            }
+        }
+    }
 
-    second_lon + w * (7.5 / 60.0) / 10.0
-}
+    #[test]
+    fn test_mesh_at_offset() {
+        let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+        let origin = coord_to_mesh(tokyo, MeshLevel::Third).unwrap();
+
+        // 移動距離0は起点のメッシュ
+        for bearing in [0.0, 90.0, 225.0] {
+            assert_eq!(
+                mesh_at_offset(tokyo, bearing, 0.0, MeshLevel::Third).unwrap(),
+                origin
+            );
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        // 東へ3kmは同じ行の東側のメッシュ
+        let east = mesh_at_offset(tokyo, 90.0, 3000.0, MeshLevel::Third).unwrap();
+        let (origin_lat, origin_lon) = origin.grid_index();
+        let (east_lat, east_lon) = east.grid_index();
+        assert_eq!(east_lat, origin_lat);
+        assert!(east_lon > origin_lon);
+
+        // 移動先までの距離と方位が指定どおり
+        let center = mesh_to_center(east);
+        let distance = crate::utils::distance::haversine_distance(tokyo, center);
+        assert!((distance - 3000.0).abs() < 1000.0, "{distance}");
+
+        // 北へ移動すると北側のメッシュ
+        let north = mesh_at_offset(tokyo, 0.0, 5000.0, MeshLevel::Third).unwrap();
+        assert!(north.grid_index().0 > origin_lat);
+        assert_eq!(north.grid_index().1, origin_lon);
+
+        // 日本の範囲外
+        assert_eq!(
+            mesh_at_offset(tokyo, 180.0, 2_000_000.0, MeshLevel::Third),
+            Err(MeshCodeError::OutOfRange)
+        );
+    }
 
     #[test]
     fn test_tokyo_station_first_mesh() {
@@ -249,6 +681,61 @@ mod tests {
         assert_eq!(mesh.as_string(), "53394611");
     }
 
+    #[test]
+    fn test_snap_to_mesh_center_same_mesh() {
+        let a = Coordinate::new(35.6812, 139.7671).unwrap();
+        let b = Coordinate::new(35.6820, 139.7680).unwrap();
+        assert_eq!(
+            coord_to_mesh(a, MeshLevel::Third).unwrap(),
+            coord_to_mesh(b, MeshLevel::Third).unwrap()
+        );
+        assert_eq!(
+            snap_to_mesh_center(a, MeshLevel::Third).unwrap(),
+            snap_to_mesh_center(b, MeshLevel::Third).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_coord_to_mesh_best_desired_level() {
+        let coord = Coordinate::new(35.6812, 139.7671).unwrap();
+        for level in [MeshLevel::Third, MeshLevel::FourthEighth, MeshLevel::Fifth] {
+            let (mesh, achieved) = coord_to_mesh_best(coord, level).unwrap();
+            assert_eq!(achieved, level);
+            assert_eq!(mesh, coord_to_mesh(coord, level).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_convert_best_degrades() {
+        // 3次より細かいレベルで検証に失敗する場合は3次メッシュまで粗くなる
+        let coord = Coordinate::new(35.6812, 139.7671).unwrap();
+        let mut tried = Vec::new();
+        let (mesh, level) = convert_best(MeshLevel::FourthEighth, |level| {
+            tried.push(level);
+            if level > MeshLevel::Third {
+                Err(crate::error::MeshCodeError::OutOfRange)
+            } else {
+                coord_to_mesh(coord, level)
+            }
+        })
+        .unwrap();
+        assert_eq!(level, MeshLevel::Third);
+        assert_eq!(mesh.as_string(), "53394611");
+        assert_eq!(
+            tried,
+            [
+                MeshLevel::FourthEighth,
+                MeshLevel::FourthQuarter,
+                MeshLevel::FourthHalf,
+                MeshLevel::Third
+            ]
+        );
+
+        // 1次メッシュでも表現できない座標はエラー
+        let far_north = Coordinate::new_unchecked(69.9, 139.0);
+        assert!(coord_to_mesh_best(far_north, MeshLevel::Third).is_err());
+    }
+
     #[test]
     fn test_subdivision_index_jis_numbering() {
         // 南西=1、南東=2、北西=3、北東=4
@@ -258,4 +745,186 @@ mod tests {
         let (ne, _, _) = subdivision_index(0.6, 0.6, 1.0, 1.0);
         assert_eq!((sw, se, nw, ne), (1, 2, 3, 4));
     }
+
+    #[test]
+    fn test_prefix_cache_matches_uncached() {
+        let mut cache = MeshPrefixCache::new();
+        // 1次・2次メッシュの境界をまたぐ座標列
+        for i in 0..200 {
+            let lat = 35.60 + 0.0007 * i as f64;
+            let lon = 139.90 + 0.0011 * i as f64;
+            let coord = Coordinate::new_unchecked(lat, lon);
+            for level in [
+                MeshLevel::First,
+                MeshLevel::Second,
+                MeshLevel::Third,
+                MeshLevel::FourthHalf,
+                MeshLevel::FourthQuarter,
+                MeshLevel::FourthEighth,
+                MeshLevel::Fifth,
+            ] {
+                let expected = MeshPrefixCache::new().convert(coord, level).unwrap();
+                assert_eq!(cache.convert(coord, level).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefix_cache_across_mesh_boundaries() {
+        // 1次メッシュ（北緯36度・東経140度）と2次メッシュの境界を行き来する座標列と、
+        // 1次〜8分の1メッシュ・5次メッシュの期待値
+        let cases = [
+            (
+                (35.9999, 139.9999),
+                [
+                    "5339",
+                    "533977",
+                    "53397799",
+                    "533977994",
+                    "5339779944",
+                    "53397799444",
+                    "5339779999",
+                ],
+            ),
+            (
+                (36.0001, 139.9999),
+                [
+                    "5439",
+                    "543907",
+                    "54390709",
+                    "543907092",
+                    "5439070922",
+                    "54390709222",
+                    "5439070909",
+                ],
+            ),
+            (
+                (36.0001, 140.0001),
+                [
+                    "5440",
+                    "544000",
+                    "54400000",
+                    "544000001",
+                    "5440000011",
+                    "54400000111",
+                    "5440000000",
+                ],
+            ),
+            (
+                (35.9999, 140.0001),
+                [
+                    "5340",
+                    "534070",
+                    "53407090",
+                    "534070903",
+                    "5340709033",
+                    "53407090333",
+                    "5340709090",
+                ],
+            ),
+            (
+                (35.6665, 139.7499),
+                [
+                    "5339",
+                    "533935",
+                    "53393599",
+                    "533935994",
+                    "5339359944",
+                    "53393599444",
+                    "5339359999",
+                ],
+            ),
+            (
+                (35.6668, 139.7501),
+                [
+                    "5339",
+                    "533946",
+                    "53394600",
+                    "533946001",
+                    "5339460011",
+                    "53394600111",
+                    "5339460000",
+                ],
+            ),
+            (
+                (35.6665, 139.7501),
+                [
+                    "5339",
+                    "533936",
+                    "53393690",
+                    "533936903",
+                    "5339369033",
+                    "53393690333",
+                    "5339369090",
+                ],
+            ),
+            (
+                (35.9999, 139.9999),
+                [
+                    "5339",
+                    "533977",
+                    "53397799",
+                    "533977994",
+                    "5339779944",
+                    "53397799444",
+                    "5339779999",
+                ],
+            ),
+        ];
+        let levels = [
+            MeshLevel::First,
+            MeshLevel::Second,
+            MeshLevel::Third,
+            MeshLevel::FourthHalf,
+            MeshLevel::FourthQuarter,
+            MeshLevel::FourthEighth,
+            MeshLevel::Fifth,
+        ];
+
+        let mut cache = MeshPrefixCache::new();
+        for ((lat, lon), expected) in cases {
+            let coord = Coordinate::new(lat, lon).unwrap();
+            for (level, code) in levels.into_iter().zip(expected) {
+                let cached = cache.convert(coord, level).unwrap();
+                assert_eq!(cached.as_string(), code, "{lat}, {lon}, {level:?}");
+                assert_eq!(coord_to_mesh(coord, level).unwrap(), cached);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fourth_eighth_on_third_mesh_ne_corner() {
+        let third = MeshCode::from_str("53394611").unwrap();
+        let ne = crate::convert::mesh_to_bounds(third).north_east();
+        let coord = Coordinate::new(ne.lat(), ne.lon()).unwrap();
+
+        let mesh = coord_to_mesh(coord, MeshLevel::FourthEighth).unwrap();
+        let suffix = mesh.code() % 1000;
+        for digit in [suffix / 100, suffix / 10 % 10, suffix % 10] {
+            assert!((1..=4).contains(&digit), "{mesh}");
+        }
+        assert!(crate::convert::mesh_to_bounds(mesh).contains(coord));
+    }
+
+    #[test]
+    fn test_subdivision_boundaries_produce_valid_codes() {
+        // 3次メッシュ内の8分の1・5次メッシュの格子線上の座標も有効なコードになる
+        let third = MeshCode::from_str("53394611").unwrap();
+        let sw = crate::convert::mesh_to_bounds(third).south_west();
+        for i in 0..=40 {
+            for j in 0..=40 {
+                let lat = sw.lat() + i as f64 * (THIRD_LAT_SIZE / 40.0);
+                let lon = sw.lon() + j as f64 * (THIRD_LON_SIZE / 40.0);
+                let coord = Coordinate::new(lat, lon).unwrap();
+                for level in [
+                    MeshLevel::FourthHalf,
+                    MeshLevel::FourthQuarter,
+                    MeshLevel::FourthEighth,
+                    MeshLevel::Fifth,
+                ] {
+                    assert!(coord_to_mesh(coord, level).is_ok(), "{lat} {lon} {level:?}");
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,124 @@
+use crate::convert::{coord_to_mesh, mesh_to_bounds};
+use crate::error::Result;
+use crate::types::{BoundingBox, Coordinate, Direction, MeshCode, MeshLevel};
+use crate::utils::distance::EARTH_RADIUS_METERS;
+use crate::utils::math;
+
+/// 座標からメッシュコードへの変換結果をまとめた診断情報
+///
+/// [`describe`]で作成します。変換先のメッシュと、その中で座標がどこに
+/// 位置しているかをまとめて確認できます。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeshDescription {
+    /// 変換元の座標
+    pub coord: Coordinate,
+    /// 座標を含むメッシュコード
+    pub mesh: MeshCode,
+    /// メッシュの境界
+    pub bounds: BoundingBox,
+    /// メッシュの中心座標
+    pub center: Coordinate,
+    /// メッシュの南西端から座標までの北方向の距離（メートル）
+    pub offset_north_m: f64,
+    /// メッシュの南西端から座標までの東方向の距離（メートル）
+    pub offset_east_m: f64,
+    /// 座標がメッシュを2×2に分けたどの区画にあるか
+    ///
+    /// [`Direction::SouthWest`]・[`Direction::SouthEast`]・[`Direction::NorthWest`]・
+    /// [`Direction::NorthEast`]のいずれかです。中心線上の座標は北側・東側とみなします。
+    pub quadrant: Direction,
+}
+
+/// 座標をメッシュコードに変換し、変換結果の診断情報を返す
+///
+/// メッシュコード・境界・中心座標に加え、南西端からの距離（メートル）と、
+/// メッシュを2×2に分けたときに座標が含まれる区画を返します。
+/// 距離は南北方向を地球半径で換算し、東西方向は座標の緯度の余弦で補正します。
+///
+/// # 引数
+/// * `coord` - 変換する座標
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// 診断情報、または変換に失敗した場合は[`coord_to_mesh`]と同じエラー
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let description = describe(tokyo, MeshLevel::Third).unwrap();
+/// assert_eq!(description.mesh.as_string(), "53394611");
+/// assert_eq!(description.quadrant, Direction::NorthWest);
+/// assert!(description.offset_north_m > 500.0);
+/// ```
+pub fn describe(coord: Coordinate, level: MeshLevel) -> Result<MeshDescription> {
+    let mesh = coord_to_mesh(coord, level)?;
+    let bounds = mesh_to_bounds(mesh);
+    let center = bounds.center();
+
+    let offset_north_m = (coord.lat() - bounds.min_lat()).to_radians() * EARTH_RADIUS_METERS;
+    let offset_east_m = (coord.lon() - bounds.min_lon()).to_radians()
+        * EARTH_RADIUS_METERS
+        * math::cos(coord.lat_rad());
+
+    let quadrant = match (coord.lat() >= center.lat(), coord.lon() >= center.lon()) {
+        (false, false) => Direction::SouthWest,
+        (false, true) => Direction::SouthEast,
+        (true, false) => Direction::NorthWest,
+        (true, true) => Direction::NorthEast,
+    };
+
+    Ok(MeshDescription {
+        coord,
+        mesh,
+        bounds,
+        center,
+        offset_north_m,
+        offset_east_m,
+        quadrant,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_offset_within_mesh() {
+        let coords = [
+            (35.6812, 139.7671),
+            (35.6437877, 139.6716674),
+            (43.0621, 141.3544),
+            (26.2124, 127.6809),
+        ];
+        for level in [MeshLevel::Third, MeshLevel::FourthQuarter, MeshLevel::Fifth] {
+            for (lat, lon) in coords {
+                let coord = Coordinate::new(lat, lon).unwrap();
+                let d = describe(coord, level).unwrap();
+                let (height, width) = level.size_meters_at(coord.lat());
+
+                assert!(d.bounds.contains(coord));
+                assert!((0.0..=height).contains(&d.offset_north_m));
+                assert!((0.0..=width).contains(&d.offset_east_m));
+
+                let (dx, dy) = d.quadrant.offset();
+                assert_eq!(dy > 0, d.offset_north_m >= height / 2.0 - 1e-6);
+                assert_eq!(dx > 0, d.offset_east_m >= width / 2.0 - 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_describe_quadrant_southwest() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let sw = mesh_to_bounds(mesh).south_west();
+        let coord = Coordinate::new(sw.lat() + 1e-5, sw.lon() + 1e-5).unwrap();
+
+        let d = describe(coord, MeshLevel::Third).unwrap();
+        assert_eq!(d.mesh, mesh);
+        assert_eq!(d.quadrant, Direction::SouthWest);
+        assert!(d.offset_north_m < 2.0 && d.offset_east_m < 2.0);
+    }
+}
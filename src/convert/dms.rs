@@ -0,0 +1,95 @@
+use crate::error::{CoordResult, CoordinateError};
+use crate::types::Coordinate;
+
+/// 度・分・秒を10進数の度に変換する（分・秒が範囲外の場合はNone）
+///
+/// 度が負の場合は値全体を負とみなします（例: -35度30分 → -35.5度）。
+fn dms_to_degrees(d: i32, m: i32, s: f64) -> Option<f64> {
+    if !(0..60).contains(&m) || !(0.0..60.0).contains(&s) {
+        return None;
+    }
+    let abs = d.unsigned_abs() as f64 + m as f64 / 60.0 + s / 3600.0;
+    Some(if d < 0 { -abs } else { abs })
+}
+
+/// 度・分・秒で表された緯度・経度から座標を作成する
+///
+/// 分は0〜59の整数、秒は0以上60未満である必要があります。
+/// 度が負の場合は値全体を負（南緯・西経）とみなします。
+///
+/// # 引数
+/// * `lat_d`, `lat_m`, `lat_s` - 緯度の度・分・秒
+/// * `lon_d`, `lon_m`, `lon_s` - 経度の度・分・秒
+///
+/// # 戻り値
+/// 座標。分・秒が範囲外の場合は[`CoordinateError::InvalidLatitude`]または
+/// [`CoordinateError::InvalidLongitude`]、それ以外は[`Coordinate::new`]と同じ検証結果
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// // 東京駅（北緯35度40分52.32秒、東経139度46分1.56秒）
+/// let coord = coord_from_dms(35, 40, 52.32, 139, 46, 1.56).unwrap();
+/// assert!((coord.lat() - 35.6812).abs() < 1e-9);
+/// assert_eq!(coord_to_mesh(coord, MeshLevel::First).unwrap().as_string(), "5339");
+/// ```
+pub fn coord_from_dms(
+    lat_d: i32,
+    lat_m: i32,
+    lat_s: f64,
+    lon_d: i32,
+    lon_m: i32,
+    lon_s: f64,
+) -> CoordResult<Coordinate> {
+    let lat = dms_to_degrees(lat_d, lat_m, lat_s)
+        .ok_or(CoordinateError::InvalidLatitude(lat_d as f64))?;
+    let lon = dms_to_degrees(lon_d, lon_m, lon_s)
+        .ok_or(CoordinateError::InvalidLongitude(lon_d as f64))?;
+    Coordinate::new(lat, lon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::coord_to_mesh;
+    use crate::types::MeshLevel;
+
+    #[test]
+    fn test_tokyo_station_dms() {
+        let coord = coord_from_dms(35, 40, 52.32, 139, 46, 1.56).unwrap();
+        assert!((coord.lat() - 35.6812).abs() < 1e-9);
+        assert!((coord.lon() - 139.7671).abs() < 1e-9);
+
+        let mesh = coord_to_mesh(coord, MeshLevel::First).unwrap();
+        assert_eq!(mesh.as_string(), "5339");
+        let mesh = coord_to_mesh(coord, MeshLevel::Third).unwrap();
+        assert_eq!(mesh.as_string(), "53394611");
+    }
+
+    #[test]
+    fn test_dms_validation() {
+        assert_eq!(
+            coord_from_dms(35, 60, 0.0, 139, 0, 0.0),
+            Err(CoordinateError::InvalidLatitude(35.0))
+        );
+        assert_eq!(
+            coord_from_dms(35, 0, 0.0, 139, -1, 0.0),
+            Err(CoordinateError::InvalidLongitude(139.0))
+        );
+        assert_eq!(
+            coord_from_dms(35, 0, 60.0, 139, 0, 0.0),
+            Err(CoordinateError::InvalidLatitude(35.0))
+        );
+        assert_eq!(
+            coord_from_dms(91, 0, 0.0, 139, 0, 0.0),
+            Err(CoordinateError::InvalidLatitude(91.0))
+        );
+        assert_eq!(
+            coord_from_dms(-35, 0, 0.0, 139, 0, 0.0),
+            Err(CoordinateError::OutOfJapanRange)
+        );
+        assert!(coord_from_dms(35, 59, 59.999, 139, 0, 0.0).is_ok());
+    }
+}
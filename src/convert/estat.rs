@@ -0,0 +1,127 @@
+//! 政府統計の総合窓口（e-Stat）の地域メッシュ統計のキーコードとの相互変換
+//!
+//! e-Statで配布される地域メッシュ統計は、メッシュを`KEY_CODE`列で識別します。
+//! 1kmメッシュ（基準地域メッシュ）は8桁の3次メッシュコード、500mメッシュ
+//! （2分の1地域メッシュ）はそれに分割番号1桁を加えた9桁、250mメッシュ
+//! （4分の1地域メッシュ）はさらに1桁を加えた10桁です。分割番号は
+//! 南西=1、南東=2、北西=3、北東=4で、[`MeshLevel::FourthHalf`]・
+//! [`MeshLevel::FourthQuarter`]のコードと一致します。
+//!
+//! 10桁のキーコードは常に250mメッシュとして扱い、5次メッシュとは解釈しません。
+
+use crate::error::{MeshCodeError, Result};
+use crate::types::{MeshCode, MeshLevel};
+use alloc::format;
+use alloc::string::String;
+
+/// キーコードの桁数に対応するメッシュレベルを返す
+fn estat_level(len: usize) -> Option<MeshLevel> {
+    match len {
+        8 => Some(MeshLevel::Third),
+        9 => Some(MeshLevel::FourthHalf),
+        10 => Some(MeshLevel::FourthQuarter),
+        _ => None,
+    }
+}
+
+/// e-Statのキーコードをメッシュコードに変換する
+///
+/// 8桁は3次メッシュ、9桁は2分の1メッシュ、10桁は4分の1メッシュとして解釈します。
+/// それ以外の桁数は[`MeshCodeError::InvalidLevel`]を返します。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::convert::estat::from_estat_key;
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = from_estat_key("5339461123").unwrap();
+/// assert_eq!(mesh.level(), MeshLevel::FourthQuarter);
+/// ```
+pub fn from_estat_key(key: &str) -> Result<MeshCode> {
+    let level = estat_level(key.len()).ok_or(MeshCodeError::InvalidLevel(key.len()))?;
+    MeshCode::parse(key, Some(level))
+}
+
+/// メッシュコードをe-Statのキーコードに変換する
+///
+/// 3次メッシュ・2分の1メッシュ・4分の1メッシュ以外のレベルは、e-Statの
+/// 1km・500m・250mメッシュ統計のキーコードに対応しないため
+/// [`MeshCodeError::InvalidFormat`]を返します。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::convert::estat::to_estat_key;
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("533946112").unwrap();
+/// assert_eq!(to_estat_key(mesh).unwrap(), "533946112");
+/// ```
+pub fn to_estat_key(mesh: MeshCode) -> Result<String> {
+    match mesh.level() {
+        MeshLevel::Third | MeshLevel::FourthHalf | MeshLevel::FourthQuarter => Ok(mesh.as_string()),
+        level => Err(MeshCodeError::InvalidFormat(format!(
+            "{} has no e-Stat key code",
+            level.label()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::coord_to_mesh;
+    use crate::types::Coordinate;
+
+    #[test]
+    fn test_estat_key_examples() {
+        // 1km・500m・250mメッシュのキーコード
+        for (key, level) in [
+            ("53394611", MeshLevel::Third),
+            ("533946114", MeshLevel::FourthHalf),
+            ("5339461141", MeshLevel::FourthQuarter),
+        ] {
+            let mesh = from_estat_key(key).unwrap();
+            assert_eq!(mesh.level(), level);
+            assert_eq!(to_estat_key(mesh).unwrap(), key);
+        }
+    }
+
+    #[test]
+    fn test_estat_key_matches_coord_to_mesh() {
+        let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+        for level in [
+            MeshLevel::Third,
+            MeshLevel::FourthHalf,
+            MeshLevel::FourthQuarter,
+        ] {
+            let mesh = coord_to_mesh(tokyo, level).unwrap();
+            assert_eq!(from_estat_key(&to_estat_key(mesh).unwrap()).unwrap(), mesh);
+        }
+    }
+
+    #[test]
+    fn test_estat_key_errors() {
+        assert_eq!(
+            from_estat_key("533946"),
+            Err(MeshCodeError::InvalidLevel(6))
+        );
+        assert_eq!(
+            from_estat_key("53394611111"),
+            Err(MeshCodeError::InvalidLevel(11))
+        );
+        // 分割番号は1〜4
+        assert_eq!(from_estat_key("533946115"), Err(MeshCodeError::OutOfRange));
+        assert!(matches!(
+            from_estat_key("5339461a"),
+            Err(MeshCodeError::InvalidDigit { .. })
+        ));
+
+        let fifth = MeshCode::parse("5339461197", Some(MeshLevel::Fifth)).unwrap();
+        assert!(matches!(
+            to_estat_key(fifth),
+            Err(MeshCodeError::InvalidFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,168 @@
+//! 気象庁の1km格子番号との相互変換
+//!
+//! 気象庁の解析雨量・降水短時間予報などの1km格子（GRIB2）は、北緯48度・東経118度を
+//! 北西端とし、緯度方向30秒・経度方向45秒の格子を北から南へ3360行、西から東へ
+//! 2560列並べたものです。格子の大きさと原点が標準地域メッシュの格子線と一致するため、
+//! 各格子は3次メッシュ（基準地域メッシュ）とちょうど1対1に対応します。
+//!
+//! このモジュールでは格子の位置を0始まりの（行、列）で表します。行は北端（北緯48度）
+//! から南へ、列は西端（東経118度）から東へ数えます。
+
+use crate::error::{MeshCodeError, Result};
+use crate::types::{MeshCode, MeshLevel};
+use alloc::string::ToString;
+
+/// 1km格子の行数（北緯48度〜20度）
+pub const JMA_1KM_ROWS: u32 = 3360;
+/// 1km格子の列数（東経118度〜150度）
+pub const JMA_1KM_COLS: u32 = 2560;
+
+/// 北緯0度から数えた北端（北緯48度）の3次メッシュ緯度方向番号（48度 × 120）
+const NORTH_EDGE_LAT_INDEX: u32 = 48 * 120;
+/// 東経100度から数えた西端（東経118度）の3次メッシュ経度方向番号（18度 × 80）
+const WEST_EDGE_LON_INDEX: u32 = 18 * 80;
+
+/// 気象庁1km格子の位置（0始まり）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JmaGridIndex {
+    /// 北端から南へ数えた行番号
+    pub row: u32,
+    /// 西端から東へ数えた列番号
+    pub col: u32,
+}
+
+/// 3次メッシュを気象庁1km格子の位置に変換する
+///
+/// 3次メッシュ以外のレベルは[`MeshCodeError::InvalidFormat`]、
+/// 格子の範囲外のメッシュは[`MeshCodeError::OutOfRange`]を返します。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::convert::jma::to_jma_1km;
+/// use jismeshcode::prelude::*;
+///
+/// let tokyo = MeshCode::from_str("53394611").unwrap();
+/// let cell = to_jma_1km(tokyo).unwrap();
+/// assert_eq!((cell.row, cell.col), (1478, 1741));
+/// ```
+pub fn to_jma_1km(mesh: MeshCode) -> Result<JmaGridIndex> {
+    if mesh.level() != MeshLevel::Third {
+        return Err(MeshCodeError::InvalidFormat(
+            "JMA 1km grid corresponds to 3rd-level meshes only".to_string(),
+        ));
+    }
+
+    let code = mesh.code();
+    let pq = (code / 1_000_000) as u32;
+    let rs = (code / 10_000 % 100) as u32;
+    let t = (code / 1000 % 10) as u32;
+    let u = (code / 100 % 10) as u32;
+    let v = (code / 10 % 10) as u32;
+    let w = (code % 10) as u32;
+
+    // 3次メッシュ単位の通し番号（緯度は北緯0度、経度は東経100度から）
+    let lat_index = pq * 80 + t * 10 + v;
+    let lon_index = rs * 80 + u * 10 + w;
+
+    if lat_index >= NORTH_EDGE_LAT_INDEX || lon_index < WEST_EDGE_LON_INDEX {
+        return Err(MeshCodeError::OutOfRange);
+    }
+    let row = NORTH_EDGE_LAT_INDEX - 1 - lat_index;
+    let col = lon_index - WEST_EDGE_LON_INDEX;
+    if row >= JMA_1KM_ROWS || col >= JMA_1KM_COLS {
+        return Err(MeshCodeError::OutOfRange);
+    }
+
+    Ok(JmaGridIndex { row, col })
+}
+
+/// 気象庁1km格子の位置を3次メッシュに変換する
+///
+/// 格子の範囲外の位置は[`MeshCodeError::OutOfRange`]を返します。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::convert::jma::{from_jma_1km, JmaGridIndex};
+///
+/// let mesh = from_jma_1km(JmaGridIndex { row: 1478, col: 1741 }).unwrap();
+/// assert_eq!(mesh.as_string(), "53394611");
+/// ```
+pub fn from_jma_1km(index: JmaGridIndex) -> Result<MeshCode> {
+    if index.row >= JMA_1KM_ROWS || index.col >= JMA_1KM_COLS {
+        return Err(MeshCodeError::OutOfRange);
+    }
+
+    let lat_index = NORTH_EDGE_LAT_INDEX - 1 - index.row;
+    let lon_index = WEST_EDGE_LON_INDEX + index.col;
+
+    let (pq, t, v) = (lat_index / 80, lat_index % 80 / 10, lat_index % 10);
+    let (rs, u, w) = (lon_index / 80, lon_index % 80 / 10, lon_index % 10);
+
+    let code = pq as u64 * 1_000_000
+        + rs as u64 * 10_000
+        + t as u64 * 1000
+        + u as u64 * 100
+        + v as u64 * 10
+        + w as u64;
+    MeshCode::new(MeshLevel::Third, code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::{coord_to_mesh, mesh_to_center};
+    use crate::types::Coordinate;
+
+    #[test]
+    fn test_tokyo_station_cell() {
+        // 東京駅（35.6812, 139.7671）を含む格子
+        let tokyo = coord_to_mesh(
+            Coordinate::new(35.6812, 139.7671).unwrap(),
+            MeshLevel::Third,
+        )
+        .unwrap();
+        let cell = to_jma_1km(tokyo).unwrap();
+        assert_eq!(
+            cell,
+            JmaGridIndex {
+                row: 1478,
+                col: 1741
+            }
+        );
+
+        // 格子定義から求めた中心座標とメッシュの中心が一致する
+        let center = mesh_to_center(tokyo);
+        let grid_lat = 48.0 - (cell.row as f64 + 0.5) / 120.0;
+        let grid_lon = 118.0 + (cell.col as f64 + 0.5) * 0.0125;
+        assert!((center.lat() - grid_lat).abs() < 1e-9);
+        assert!((center.lon() - grid_lon).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_roundtrip_and_corners() {
+        for (row, col) in [(0, 0), (0, 2559), (3359, 0), (3359, 2559), (1478, 1741)] {
+            let index = JmaGridIndex { row, col };
+            let mesh = from_jma_1km(index).unwrap();
+            assert_eq!(to_jma_1km(mesh).unwrap(), index);
+        }
+
+        // 北西端の格子は北緯48度・東経118度に接する
+        let nw = from_jma_1km(JmaGridIndex { row: 0, col: 0 }).unwrap();
+        let bounds = crate::convert::mesh_to_bounds(nw);
+        assert!((bounds.max_lat() - 48.0).abs() < 1e-9);
+        assert!((bounds.min_lon() - 118.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_out_of_grid() {
+        assert!(from_jma_1km(JmaGridIndex { row: 3360, col: 0 }).is_err());
+        assert!(from_jma_1km(JmaGridIndex { row: 0, col: 2560 }).is_err());
+        // 東経150度以東は格子の範囲外
+        let east = MeshCode::from_str("53510000").unwrap();
+        assert_eq!(to_jma_1km(east), Err(MeshCodeError::OutOfRange));
+        // 3次メッシュ以外は変換できない
+        assert!(to_jma_1km(MeshCode::from_str("533946").unwrap()).is_err());
+    }
+}
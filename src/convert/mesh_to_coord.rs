@@ -1,4 +1,6 @@
+use crate::error::{MeshCodeError, Result};
 use crate::types::{BoundingBox, Coordinate, MeshCode, MeshLevel};
+use alloc::format;
 
 pub fn mesh_to_bounds(mesh: MeshCode) -> BoundingBox {
     let level = mesh.level();
@@ -23,11 +25,123 @@ pub fn mesh_to_bounds(mesh: MeshCode) -> BoundingBox {
     BoundingBox::new(sw, ne)
 }
 
+/// コード値の桁数を検証してからメッシュの境界を計算する
+///
+/// [`MeshCode::new`]は先頭のゼロを含むコード値を受け付けるため、
+/// `MeshCode::new(MeshLevel::Third, 5339)`は`"00005339"`という3次メッシュになり、
+/// [`mesh_to_bounds`]は北緯0度・東経100度付近の意味のない範囲を返します。
+/// この関数はコード値の有効桁数がレベルの桁数と一致しない場合に
+/// [`MeshCodeError::InvalidFormat`]を返します。
+///
+/// 先頭桁が0のコードは、`MeshCode::from_str("0001")`のように文字列から作成した
+/// 有効なコードでも意図的に拒否します。1次メッシュの緯度番号が0〜9のメッシュは
+/// 北緯0〜6.7度付近にあり日本の範囲（北緯20〜46度）に含まれないため、
+/// 先頭のゼロは整数として保存した際などに桁が欠落した兆候とみなせるからです。
+/// 先頭が0のコードの範囲が必要な場合は[`mesh_to_bounds`]を使用します。
+///
+/// # 引数
+/// * `mesh` - メッシュコード
+///
+/// # 戻り値
+/// メッシュの境界ボックス、またはコード値の桁数が不正な場合はエラー
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// assert_eq!(try_mesh_to_bounds(mesh).unwrap(), mesh_to_bounds(mesh));
+///
+/// let short = MeshCode::new(MeshLevel::Third, 5339).unwrap();
+/// assert!(try_mesh_to_bounds(short).is_err());
+/// ```
+pub fn try_mesh_to_bounds(mesh: MeshCode) -> Result<BoundingBox> {
+    let level = mesh.level();
+    let code = mesh.code();
+    let len = level.code_length() as u32;
+    if !(10u64.pow(len - 1)..10u64.pow(len)).contains(&code) {
+        return Err(MeshCodeError::InvalidFormat(format!(
+            "code {code} does not have {len} digits for {level:?}"
+        )));
+    }
+    Ok(mesh_to_bounds(mesh))
+}
+
 pub fn mesh_to_center(mesh: MeshCode) -> Coordinate {
     let bounds = mesh_to_bounds(mesh);
     bounds.center()
 }
 
+/// メッシュの南西端と北東端の大円上の中点を返す
+///
+/// [`mesh_to_center`]は境界の緯度・経度をそれぞれ平均した平面上の中点です。
+/// この関数は対角の2点を[`Coordinate::midpoint`]で球面上の中点として求めます。
+/// 3次メッシュ程度の小さいメッシュでは両者はほぼ一致し、1次メッシュでは
+/// 緯度方向にわずかな差（0.01度未満）が生じます。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("5339").unwrap();
+/// let geodesic = mesh_geodesic_center(mesh);
+/// let planar = mesh_to_center(mesh);
+/// assert!((geodesic.lat() - planar.lat()).abs() < 0.01);
+/// ```
+pub fn mesh_geodesic_center(mesh: MeshCode) -> Coordinate {
+    let bounds = mesh_to_bounds(mesh);
+    bounds.south_west().midpoint(bounds.north_east())
+}
+
+/// 複数のメッシュの中心座標を緯度・経度別の配列へ書き出す
+///
+/// 各メッシュの[`mesh_to_center`]の結果を、`out_lat[i]`・`out_lon[i]`に書き込みます。
+/// 緯度と経度を別々の連続した配列（Structure of Arrays）に置くため、
+/// 大量のメッシュ中心をSIMD演算やGPUへの転送にそのまま渡せます。
+///
+/// # 引数
+/// * `meshes` - メッシュコードのスライス
+/// * `out_lat` - 中心の緯度を書き込むスライス
+/// * `out_lon` - 中心の経度を書き込むスライス
+///
+/// # パニック
+/// `out_lat`・`out_lon`の長さが`meshes`の長さと異なる場合
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let meshes = [
+///     MeshCode::from_str("53394611").unwrap(),
+///     MeshCode::from_str("53394612").unwrap(),
+/// ];
+/// let mut lat = [0.0; 2];
+/// let mut lon = [0.0; 2];
+/// mesh_centers(&meshes, &mut lat, &mut lon);
+/// assert_eq!(lat[1], mesh_to_center(meshes[1]).lat());
+/// ```
+pub fn mesh_centers(meshes: &[MeshCode], out_lat: &mut [f64], out_lon: &mut [f64]) {
+    assert_eq!(
+        meshes.len(),
+        out_lat.len(),
+        "out_lat length must match meshes"
+    );
+    assert_eq!(
+        meshes.len(),
+        out_lon.len(),
+        "out_lon length must match meshes"
+    );
+
+    for ((mesh, lat), lon) in meshes.iter().zip(out_lat).zip(out_lon) {
+        let center = mesh_to_center(*mesh);
+        *lat = center.lat();
+        *lon = center.lon();
+    }
+}
+
 fn calc_first_mesh_sw(code_str: &str) -> (f64, f64) {
     let p = code_str[0..1].parse::<f64>().unwrap();
     let q = code_str[1..2].parse::<f64>().unwrap();
@@ -68,7 +182,10 @@ fn calc_third_mesh_sw(code_str: &str) -> (f64, f64) {
 ///
 /// JIS X 0410の番号付け（南西=1、南東=2、北西=3、北東=4）に対応します。
 /// 戻り値は（緯度方向、経度方向）で、各値は0または1です。
+///
+/// 番号が1〜4であることは[`MeshCode::new`]で検証済みです。
 fn subdivision_offset_units(digit: u32) -> (f64, f64) {
+    debug_assert!((1..=4).contains(&digit), "subdivision digit {digit}");
     let index = digit - 1;
     ((index / 2) as f64, (index % 2) as f64)
 }
@@ -129,6 +246,76 @@ fn calc_fifth_mesh_sw(code_str: &str) -> (f64, f64) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_mesh_geodesic_center() {
+        let first = MeshCode::from_str("5339").unwrap();
+        let geodesic = mesh_geodesic_center(first);
+        let planar = mesh_to_center(first);
+        let diff = (geodesic.lat() - planar.lat())
+            .abs()
+            .max((geodesic.lon() - planar.lon()).abs());
+        assert!(diff > 0.0 && diff < 0.01, "diff = {diff}");
+        assert!(mesh_to_bounds(first).contains(geodesic));
+
+        let third = MeshCode::from_str("53394611").unwrap();
+        let geodesic = mesh_geodesic_center(third);
+        let planar = mesh_to_center(third);
+        assert!((geodesic.lat() - planar.lat()).abs() < 1e-6);
+        assert!((geodesic.lon() - planar.lon()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_mesh_centers_matches_mesh_to_center() {
+        let meshes: alloc::vec::Vec<MeshCode> = [
+            "5339",
+            "533946",
+            "53394611",
+            "533946113",
+            "5339461134",
+            "0001",
+        ]
+        .iter()
+        .map(|s| MeshCode::from_str(s).unwrap())
+        .collect();
+        let mut lat = alloc::vec![0.0; meshes.len()];
+        let mut lon = alloc::vec![0.0; meshes.len()];
+        mesh_centers(&meshes, &mut lat, &mut lon);
+
+        for (i, &mesh) in meshes.iter().enumerate() {
+            let center = mesh_to_center(mesh);
+            assert_eq!(lat[i], center.lat());
+            assert_eq!(lon[i], center.lon());
+        }
+
+        mesh_centers(&[], &mut [], &mut []);
+    }
+
+    #[test]
+    #[should_panic(expected = "out_lon length")]
+    fn test_mesh_centers_length_mismatch() {
+        let meshes = [MeshCode::from_str("53394611").unwrap()];
+        mesh_centers(&meshes, &mut [0.0], &mut [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_try_mesh_to_bounds_rejects_short_code() {
+        let short = MeshCode::new(MeshLevel::Third, 5339).unwrap();
+        assert!(matches!(
+            try_mesh_to_bounds(short),
+            Err(MeshCodeError::InvalidFormat(_))
+        ));
+
+        // 先頭が0のコードは文字列から作成した場合も意図的に拒否する
+        let padded = MeshCode::from_str("0001").unwrap();
+        assert!(try_mesh_to_bounds(padded).is_err());
+        assert_eq!(mesh_to_bounds(padded).min_lat(), 0.0);
+
+        for code in ["5339", "533946", "53394611", "533946114", "5339461197"] {
+            let mesh = MeshCode::from_str(code).unwrap();
+            assert_eq!(try_mesh_to_bounds(mesh).unwrap(), mesh_to_bounds(mesh));
+        }
+    }
+
     #[test]
     fn test_first_mesh_bounds() {
         let mesh = MeshCode::from_str("5339").unwrap();
@@ -149,4 +336,29 @@ mod tests {
         assert!(center.lat() >= 35.0 && center.lat() <= 36.0);
         assert!(center.lon() >= 139.0 && center.lon() <= 140.0);
     }
+
+    #[test]
+    fn test_zero_suffixes() {
+        // 分割地域メッシュの番号は1〜4のため、0を含むコードは作成時にエラーになる
+        for (code, level) in [
+            ("533946110", MeshLevel::FourthHalf),
+            ("5339461100", MeshLevel::FourthQuarter),
+            ("5339461110", MeshLevel::FourthQuarter),
+            ("53394611100", MeshLevel::FourthEighth),
+            ("53394611000", MeshLevel::FourthEighth),
+        ] {
+            assert_eq!(
+                MeshCode::parse(code, Some(level)),
+                Err(MeshCodeError::OutOfRange)
+            );
+        }
+
+        // 5次メッシュの番号は緯度・経度方向それぞれ0〜9で、末尾00は3次メッシュの南西端
+        let fifth = MeshCode::parse("5339461100", Some(MeshLevel::Fifth)).unwrap();
+        let third = mesh_to_bounds(MeshCode::from_str("53394611").unwrap());
+        let bounds = mesh_to_bounds(fifth);
+        assert_eq!(bounds.south_west(), third.south_west());
+        assert!(bounds.max_lat() < third.max_lat());
+        assert!(bounds.max_lon() < third.max_lon());
+    }
 }
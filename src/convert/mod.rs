@@ -1,5 +1,26 @@
+#[cfg(feature = "std")]
+mod cache;
 mod coord_to_mesh;
+mod describe;
+mod dms;
+pub mod estat;
+pub mod jma;
 mod mesh_to_coord;
+pub mod projection;
+pub mod tiles;
+mod traits;
 
-pub use coord_to_mesh::coord_to_mesh;
-pub use mesh_to_coord::{mesh_to_bounds, mesh_to_center};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use cache::ConversionCache;
+pub(crate) use coord_to_mesh::MeshPrefixCache;
+pub use coord_to_mesh::{
+    coord_to_mesh, coord_to_mesh_best, coord_to_mesh_tolerant, mesh_at_offset,
+    postal_centroid_to_mesh, snap_to_mesh_center,
+};
+pub use describe::{describe, MeshDescription};
+pub use dms::coord_from_dms;
+pub use mesh_to_coord::{
+    mesh_centers, mesh_geodesic_center, mesh_to_bounds, mesh_to_center, try_mesh_to_bounds,
+};
+pub use traits::{ToCoordinate, ToMeshCode};
@@ -0,0 +1,186 @@
+//! 平面直角座標系（JGD2011）から緯度経度への変換
+//!
+//! 測量成果などで用いられる19の平面直角座標系（平成14年国土交通省告示第9号）の
+//! 座標（X: 北向き、Y: 東向き、単位メートル）を、JGD2011の緯度経度に変換します。
+//! 得られた座標を[`crate::coord_to_mesh`]に渡すことでメッシュコードを求められます。
+//!
+//! 変換式は国土地理院が採用しているガウス・クリューゲル図法の逆変換
+//! （河瀬和重「Gauss-Krüger投影における経緯度座標及び平面直角座標相互間の座標換算に
+//! ついてのより簡明な計算方法」国土地理院時報 121集, 2011）で、第3扁平率`n`の
+//! 6次までの級数展開を用います。楕円体はGRS80（長半径6378137m、
+//! 逆扁平率298.257222101）、原点の縮尺係数は0.9999です。
+//!
+//! # 例
+//!
+//! ```
+//! use jismeshcode::convert::projection::plane_rect_to_coord;
+//! use jismeshcode::prelude::*;
+//!
+//! // IX系（東京都など）の座標から3次メッシュを求める
+//! let coord = plane_rect_to_coord(9, -35367.230, -5995.185).unwrap();
+//! let mesh = coord_to_mesh(coord, MeshLevel::Third).unwrap();
+//! assert_eq!(mesh.as_string(), "53394611");
+//! ```
+
+use crate::error::{CoordResult, CoordinateError};
+use crate::types::Coordinate;
+use crate::utils::math;
+
+/// GRS80楕円体の長半径（メートル）
+const SEMI_MAJOR_AXIS: f64 = 6378137.0;
+/// GRS80楕円体の逆扁平率
+const INVERSE_FLATTENING: f64 = 298.257222101;
+/// 座標系原点の縮尺係数
+const SCALE_FACTOR: f64 = 0.9999;
+
+/// 各系の原点の緯度・経度（度）。添字0がI系
+const ORIGINS: [(f64, f64); 19] = [
+    (33.0, 129.5),               // I
+    (33.0, 131.0),               // II
+    (36.0, 132.0 + 10.0 / 60.0), // III
+    (33.0, 133.5),               // IV
+    (36.0, 134.0 + 20.0 / 60.0), // V
+    (36.0, 136.0),               // VI
+    (36.0, 137.0 + 10.0 / 60.0), // VII
+    (36.0, 138.5),               // VIII
+    (36.0, 139.0 + 50.0 / 60.0), // IX
+    (40.0, 140.0 + 50.0 / 60.0), // X
+    (44.0, 140.25),              // XI
+    (44.0, 142.25),              // XII
+    (44.0, 144.25),              // XIII
+    (26.0, 142.0),               // XIV
+    (26.0, 127.5),               // XV
+    (26.0, 124.0),               // XVI
+    (26.0, 131.0),               // XVII
+    (20.0, 136.0),               // XVIII
+    (26.0, 154.0),               // XIX
+];
+
+/// 平面直角座標を緯度経度に変換する
+///
+/// # 引数
+/// * `system` - 系番号（1〜19、I系〜XIX系）
+/// * `x` - X座標（原点から北向き、メートル）
+/// * `y` - Y座標（原点から東向き、メートル）
+///
+/// # 戻り値
+/// 緯度経度の座標。系番号が範囲外の場合は[`CoordinateError::InvalidPlaneRectSystem`]、
+/// 変換結果が日本の範囲外の場合は[`CoordinateError::OutOfJapanRange`]を返します。
+pub fn plane_rect_to_coord(system: u8, x: f64, y: f64) -> CoordResult<Coordinate> {
+    let &(origin_lat, origin_lon) = system
+        .checked_sub(1)
+        .and_then(|index| ORIGINS.get(index as usize))
+        .ok_or(CoordinateError::InvalidPlaneRectSystem(system))?;
+
+    let n = 1.0 / (2.0 * INVERSE_FLATTENING - 1.0);
+    let n2 = n * n;
+    let n3 = n2 * n;
+    let n4 = n3 * n;
+    let n5 = n4 * n;
+    let n6 = n5 * n;
+
+    // 子午線弧長の係数
+    let a = [
+        1.0 + n2 / 4.0 + n4 / 64.0,
+        -1.5 * (n - n3 / 8.0 - n5 / 64.0),
+        15.0 / 16.0 * (n2 - n4 / 4.0),
+        -35.0 / 48.0 * (n3 - 5.0 / 16.0 * n5),
+        315.0 / 512.0 * n4,
+        -693.0 / 1280.0 * n5,
+    ];
+    let beta = [
+        n / 2.0 - 2.0 / 3.0 * n2 + 37.0 / 96.0 * n3 - n4 / 360.0 - 81.0 / 512.0 * n5,
+        n2 / 48.0 + n3 / 15.0 - 437.0 / 1440.0 * n4 + 46.0 / 105.0 * n5,
+        17.0 / 480.0 * n3 - 37.0 / 840.0 * n4 - 209.0 / 4480.0 * n5,
+        4397.0 / 161280.0 * n4 - 11.0 / 504.0 * n5,
+        4583.0 / 161280.0 * n5,
+    ];
+    let delta = [
+        2.0 * n - 2.0 / 3.0 * n2 - 2.0 * n3 + 116.0 / 45.0 * n4 + 26.0 / 45.0 * n5
+            - 2854.0 / 675.0 * n6,
+        7.0 / 3.0 * n2 - 8.0 / 5.0 * n3 - 227.0 / 45.0 * n4
+            + 2704.0 / 315.0 * n5
+            + 2323.0 / 945.0 * n6,
+        56.0 / 15.0 * n3 - 136.0 / 35.0 * n4 - 1262.0 / 105.0 * n5 + 73814.0 / 2835.0 * n6,
+        4279.0 / 630.0 * n4 - 332.0 / 35.0 * n5 - 399572.0 / 14175.0 * n6,
+        4174.0 / 315.0 * n5 - 144838.0 / 6237.0 * n6,
+        601676.0 / 22275.0 * n6,
+    ];
+
+    // 原点の緯度に対応する子午線弧長（縮尺係数込み）
+    let scale = SCALE_FACTOR * SEMI_MAJOR_AXIS / (1.0 + n);
+    let phi0 = origin_lat.to_radians();
+    let mut meridian_arc = a[0] * phi0;
+    for (j, coef) in a.iter().enumerate().skip(1) {
+        meridian_arc += coef * math::sin(2.0 * j as f64 * phi0);
+    }
+    let a_bar = scale * a[0];
+    let s_bar = scale * meridian_arc;
+
+    let xi = (x + s_bar) / a_bar;
+    let eta = y / a_bar;
+
+    let mut xi2 = xi;
+    let mut eta2 = eta;
+    for (j, b) in beta.iter().enumerate() {
+        let k = 2.0 * (j + 1) as f64;
+        xi2 -= b * math::sin(k * xi) * math::cosh(k * eta);
+        eta2 -= b * math::cos(k * xi) * math::sinh(k * eta);
+    }
+
+    let chi = math::asin(math::sin(xi2) / math::cosh(eta2));
+    let mut lat = chi;
+    for (j, d) in delta.iter().enumerate() {
+        lat += d * math::sin(2.0 * (j + 1) as f64 * chi);
+    }
+    let lon = origin_lon.to_radians() + math::atan2(math::sinh(eta2), math::cos(xi2));
+
+    Coordinate::new(lat.to_degrees(), lon.to_degrees())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::coord_to_mesh;
+    use crate::types::MeshLevel;
+
+    #[test]
+    fn test_origin_maps_to_origin() {
+        for (i, &(lat, lon)) in ORIGINS.iter().enumerate() {
+            let coord = plane_rect_to_coord(i as u8 + 1, 0.0, 0.0).unwrap();
+            assert!((coord.lat() - lat).abs() < 1e-9, "system {}", i + 1);
+            assert!((coord.lon() - lon).abs() < 1e-9, "system {}", i + 1);
+        }
+    }
+
+    #[test]
+    fn test_known_point_to_mesh() {
+        // 東京駅（北緯35.6812度、東経139.7671度）のIX系座標
+        let coord = plane_rect_to_coord(9, -35367.230133, -5995.185166).unwrap();
+        assert!((coord.lat() - 35.6812).abs() < 1e-8);
+        assert!((coord.lon() - 139.7671).abs() < 1e-8);
+
+        let mesh = coord_to_mesh(coord, MeshLevel::Third).unwrap();
+        assert_eq!(mesh.as_string(), "53394611");
+    }
+
+    #[test]
+    fn test_meridian_distance() {
+        // 中央子午線上で北へ55476.274m（数値積分による子午線弧長 × 0.9999）は北緯36.5度
+        let coord = plane_rect_to_coord(9, 55476.273783, 0.0).unwrap();
+        assert!((coord.lat() - 36.5).abs() < 1e-8);
+        assert!((coord.lon() - (139.0 + 50.0 / 60.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_invalid_system() {
+        assert_eq!(
+            plane_rect_to_coord(0, 0.0, 0.0),
+            Err(CoordinateError::InvalidPlaneRectSystem(0))
+        );
+        assert_eq!(
+            plane_rect_to_coord(20, 0.0, 0.0),
+            Err(CoordinateError::InvalidPlaneRectSystem(20))
+        );
+    }
+}
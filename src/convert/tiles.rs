@@ -0,0 +1,133 @@
+//! Webメルカトルのタイル座標（XYZ形式、`z/x/y`）との変換
+//!
+//! Webの地図サービスで使われるスリッピーマップのタイル座標と、メッシュコードや
+//! 境界ボックスとを対応付けます。タイルはズームレベル`z`で東西・南北をそれぞれ
+//! `2^z`分割したもので、`x`は西経180度から東へ、`y`は北緯約85.05度から南へ数えます。
+
+use crate::types::{BoundingBox, Coordinate, MeshCode, JAPAN_BOUNDS};
+use crate::utils::math;
+use core::f64::consts::PI;
+
+/// 扱うズームレベルの最大値（タイル番号が`u32`に収まる範囲）
+pub const MAX_ZOOM: u8 = 31;
+
+/// ズームレベルでの東西・南北方向のタイル数
+fn tile_count(zoom: u8) -> f64 {
+    (1u64 << zoom.min(MAX_ZOOM)) as f64
+}
+
+/// 座標を含むタイルのタイル座標`(x, y)`を求める
+fn coord_to_tile(coord: Coordinate, zoom: u8) -> (u32, u32) {
+    let n = tile_count(zoom);
+    let x = (coord.lon() + 180.0) / 360.0 * n;
+    let sin_lat = math::sin(coord.lat_rad());
+    let y = (0.5 - math::ln((1.0 + sin_lat) / (1.0 - sin_lat)) / (4.0 * PI)) * n;
+
+    let max = n - 1.0;
+    (
+        math::floor(x).clamp(0.0, max) as u32,
+        math::floor(y).clamp(0.0, max) as u32,
+    )
+}
+
+/// タイルの北西端から数えた`y`番目の境界の緯度を求める
+fn tile_y_to_lat(y: f64, n: f64) -> f64 {
+    let t = PI * (1.0 - 2.0 * y / n);
+    math::atan2(math::sinh(t), 1.0).to_degrees()
+}
+
+/// メッシュの中心座標を含むWebメルカトルのタイル座標を求める
+///
+/// # 引数
+/// * `mesh` - メッシュコード
+/// * `zoom` - ズームレベル（[`MAX_ZOOM`]より大きい値は[`MAX_ZOOM`]として扱います）
+///
+/// # 戻り値
+/// タイル座標`(x, y)`
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+/// use jismeshcode::convert::tiles::mesh_to_tile;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// assert_eq!(mesh_to_tile(mesh, 14), (14553, 6451));
+/// ```
+pub fn mesh_to_tile(mesh: MeshCode, zoom: u8) -> (u32, u32) {
+    coord_to_tile(crate::convert::mesh_to_center(mesh), zoom)
+}
+
+/// タイル座標の範囲を境界ボックスとして返す
+///
+/// 境界は日本の範囲（北緯20〜46度、東経122〜154度）にクランプします。
+/// 日本の範囲と重ならないタイルでは、クランプにより幅または高さが0の
+/// 境界ボックスになります。
+///
+/// # 引数
+/// * `z` - ズームレベル（[`MAX_ZOOM`]より大きい値は[`MAX_ZOOM`]として扱います）
+/// * `x` - 西から数えたタイル番号
+/// * `y` - 北から数えたタイル番号
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+/// use jismeshcode::convert::tiles::{mesh_to_tile, tile_to_bbox};
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let (x, y) = mesh_to_tile(mesh, 14);
+/// assert!(tile_to_bbox(14, x, y).contains(mesh_to_center(mesh)));
+/// ```
+pub fn tile_to_bbox(z: u8, x: u32, y: u32) -> BoundingBox {
+    let n = tile_count(z);
+    let (x, y) = (x as f64, y as f64);
+
+    let clamp_lat = |lat: f64| lat.clamp(JAPAN_BOUNDS.min_lat(), JAPAN_BOUNDS.max_lat());
+    let clamp_lon = |lon: f64| lon.clamp(JAPAN_BOUNDS.min_lon(), JAPAN_BOUNDS.max_lon());
+
+    let min_lon = clamp_lon(x / n * 360.0 - 180.0);
+    let max_lon = clamp_lon((x + 1.0) / n * 360.0 - 180.0);
+    let max_lat = clamp_lat(tile_y_to_lat(y, n));
+    let min_lat = clamp_lat(tile_y_to_lat(y + 1.0, n));
+
+    BoundingBox::new(
+        Coordinate::new_unchecked(min_lat, min_lon),
+        Coordinate::new_unchecked(max_lat, max_lon),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::mesh_to_center;
+
+    #[test]
+    fn test_mesh_to_tile_tokyo_zoom14() {
+        // 東京駅付近の3次メッシュ
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        assert_eq!(mesh_to_tile(mesh, 14), (14553, 6451));
+        assert_eq!(mesh_to_tile(mesh, 0), (0, 0));
+    }
+
+    #[test]
+    fn test_tile_to_bbox_tokyo_zoom14() {
+        let bbox = tile_to_bbox(14, 14553, 6451);
+        assert!((bbox.min_lon() - 139.76806640625).abs() < 1e-9);
+        assert!((bbox.max_lon() - 139.7900390625).abs() < 1e-9);
+        assert!((bbox.max_lat() - 35.6929946320988).abs() < 1e-9);
+        assert!((bbox.min_lat() - 35.67514743608467).abs() < 1e-9);
+
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        assert!(bbox.contains(mesh_to_center(mesh)));
+    }
+
+    #[test]
+    fn test_tile_to_bbox_clamped_to_japan() {
+        let bbox = tile_to_bbox(0, 0, 0);
+        assert_eq!(bbox.min_lat(), 20.0);
+        assert_eq!(bbox.max_lat(), 46.0);
+        assert_eq!(bbox.min_lon(), 122.0);
+        assert_eq!(bbox.max_lon(), 154.0);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::convert::{coord_to_mesh, mesh_to_center};
+use crate::error::{MeshCodeError, Result};
+use crate::types::{BoundingBox, Coordinate, MeshCode, MeshLevel};
+
+/// メッシュコードに変換できる型
+///
+/// 座標や`(緯度, 経度)`の組など、メッシュコードに変換できる値を
+/// レベルを指定して同じ方法で変換するためのトレイトです。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// fn meshes_of<T: ToMeshCode>(items: &[T], level: MeshLevel) -> Vec<MeshCode> {
+///     items.iter().filter_map(|item| item.to_mesh(level).ok()).collect()
+/// }
+///
+/// let points = [(35.6812, 139.7671), (34.7025, 135.4959)];
+/// let meshes = meshes_of(&points, MeshLevel::First);
+/// assert_eq!(meshes[0].as_string(), "5339");
+/// assert_eq!(meshes[1].as_string(), "5235");
+/// ```
+pub trait ToMeshCode {
+    /// 指定レベルのメッシュコードに変換する
+    fn to_mesh(&self, level: MeshLevel) -> Result<MeshCode>;
+}
+
+impl ToMeshCode for Coordinate {
+    /// [`coord_to_mesh`]で変換します。
+    fn to_mesh(&self, level: MeshLevel) -> Result<MeshCode> {
+        coord_to_mesh(*self, level)
+    }
+}
+
+impl ToMeshCode for (f64, f64) {
+    /// `(緯度, 経度)`として変換します。
+    ///
+    /// [`Coordinate::new`]で作成できない座標は[`MeshCodeError::OutOfRange`]になります。
+    fn to_mesh(&self, level: MeshLevel) -> Result<MeshCode> {
+        let coord = Coordinate::new(self.0, self.1).map_err(|_| MeshCodeError::OutOfRange)?;
+        coord_to_mesh(coord, level)
+    }
+}
+
+/// 代表となる座標に変換できる型
+///
+/// メッシュコードや境界ボックスなど、範囲を持つ値の代表点（中心座標）を
+/// 同じ方法で取得するためのトレイトです。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// assert_eq!(mesh.to_coordinate(), mesh_to_center(mesh));
+/// assert_eq!(mesh_to_bounds(mesh).to_coordinate(), mesh_to_center(mesh));
+/// ```
+pub trait ToCoordinate {
+    /// 代表となる座標を返す
+    fn to_coordinate(&self) -> Coordinate;
+}
+
+impl ToCoordinate for MeshCode {
+    /// [`mesh_to_center`]の中心座標を返します。
+    fn to_coordinate(&self) -> Coordinate {
+        mesh_to_center(*self)
+    }
+}
+
+impl ToCoordinate for BoundingBox {
+    /// [`BoundingBox::center`]の中心座標を返します。
+    fn to_coordinate(&self) -> Coordinate {
+        self.center()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_third<T: ToMeshCode>(item: &T) -> Result<MeshCode> {
+        item.to_mesh(MeshLevel::Third)
+    }
+
+    fn mesh_of<T: ToCoordinate>(item: &T, level: MeshLevel) -> MeshCode {
+        item.to_coordinate().to_mesh(level).unwrap()
+    }
+
+    #[test]
+    fn test_generic_to_mesh_code() {
+        let coord = Coordinate::new(35.6812, 139.7671).unwrap();
+        let expected = coord_to_mesh(coord, MeshLevel::Third).unwrap();
+
+        assert_eq!(to_third(&coord).unwrap(), expected);
+        assert_eq!(to_third(&(35.6812, 139.7671)).unwrap(), expected);
+        assert_eq!(to_third(&(10.0, 139.0)), Err(MeshCodeError::OutOfRange));
+        assert_eq!(to_third(&(95.0, 139.0)), Err(MeshCodeError::OutOfRange));
+    }
+
+    #[test]
+    fn test_to_coordinate() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        assert_eq!(mesh.to_coordinate(), mesh_to_center(mesh));
+
+        let bounds = crate::convert::mesh_to_bounds(mesh);
+        assert_eq!(bounds.to_coordinate(), bounds.center());
+
+        assert_eq!(mesh_of(&mesh, MeshLevel::Third), mesh);
+        assert_eq!(mesh_of(&bounds, MeshLevel::Second).as_string(), "533946");
+    }
+}
@@ -1,7 +1,12 @@
 use alloc::string::String;
 use core::fmt;
 
+/// メッシュコードの作成・変換のエラー
+///
+/// 今後バリアントが追加される可能性があるため、`match`では
+/// ワイルドカードの分岐が必要です。
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum MeshCodeError {
     InvalidFormat(String),
     InvalidDigit { position: usize, digit: char },
@@ -27,11 +32,18 @@ impl fmt::Display for MeshCodeError {
 #[cfg(feature = "std")]
 impl std::error::Error for MeshCodeError {}
 
+/// 座標の作成・変換のエラー
+///
+/// 今後バリアントが追加される可能性があるため、`match`では
+/// ワイルドカードの分岐が必要です。
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum CoordinateError {
     InvalidLatitude(f64),
     InvalidLongitude(f64),
     OutOfJapanRange,
+    /// 平面直角座標系の系番号が1〜19の範囲外
+    InvalidPlaneRectSystem(u8),
 }
 
 impl fmt::Display for CoordinateError {
@@ -46,6 +58,12 @@ impl fmt::Display for CoordinateError {
             CoordinateError::OutOfJapanRange => {
                 write!(f, "Coordinate is outside of Japan's mesh code range")
             }
+            CoordinateError::InvalidPlaneRectSystem(system) => {
+                write!(
+                    f,
+                    "Invalid plane rectangular coordinate system: {system} (must be between 1 and 19)"
+                )
+            }
         }
     }
 }
@@ -53,5 +71,41 @@ impl fmt::Display for CoordinateError {
 #[cfg(feature = "std")]
 impl std::error::Error for CoordinateError {}
 
+/// 隣接メッシュを取得できない理由
+///
+/// 今後バリアントが追加される可能性があるため、`match`では
+/// ワイルドカードの分岐が必要です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NeighborError {
+    /// 隣接メッシュが日本のメッシュ範囲（北緯20〜46度、東経122〜154度）の外にある
+    OutOfRange,
+    /// 隣接位置からメッシュコードへの変換に失敗した
+    ConversionFailed(MeshCodeError),
+}
+
+impl fmt::Display for NeighborError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NeighborError::OutOfRange => {
+                write!(f, "Neighbor is outside of Japan's mesh code range")
+            }
+            NeighborError::ConversionFailed(err) => {
+                write!(f, "Failed to convert neighbor position: {err}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NeighborError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NeighborError::OutOfRange => None,
+            NeighborError::ConversionFailed(err) => Some(err),
+        }
+    }
+}
+
 pub type Result<T> = core::result::Result<T, MeshCodeError>;
 pub type CoordResult<T> = core::result::Result<T, CoordinateError>;
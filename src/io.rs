@@ -0,0 +1,225 @@
+//! メッシュコードの集合をコンパクトなバイト列に変換する
+//!
+//! ディスクへのキャッシュなど、大量のメッシュコードを保存する用途向けの形式です。
+//!
+//! # 形式
+//!
+//! | 位置 | 内容 |
+//! |------|------|
+//! | 1バイト目 | 形式のバージョン（現在は[`FORMAT_VERSION`](crate::io::FORMAT_VERSION) = 1） |
+//! | 続くvarint | メッシュコードの個数 |
+//! | 続くvarint列 | 各メッシュコードの値と直前の値との差分 |
+//!
+//! メッシュコードの値は、上位8ビットにレベル番号（[`MeshLevel::as_u8`]）、
+//! 下位56ビットにコード値を格納したu64です。値を昇順に並べ、先頭は0との差分、
+//! 以降は直前の値との差分をvarint（LEB128、下位7ビットずつ、継続ビット付き）で
+//! 格納します。近接したメッシュの集合では差分が小さくなるため、1件あたり
+//! 1〜2バイト程度に収まります。
+
+use crate::error::{MeshCodeError, Result};
+use crate::types::{MeshCode, MeshLevel};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// 形式のバージョン番号
+pub const FORMAT_VERSION: u8 = 1;
+
+/// レベル番号とコード値を1つのu64にまとめる
+fn pack(mesh: MeshCode) -> u64 {
+    ((mesh.level().as_u8() as u64) << 56) | mesh.code()
+}
+
+/// [`pack`]でまとめた値からメッシュコードを復元する
+fn unpack(value: u64) -> Result<MeshCode> {
+    let level = MeshLevel::from_u8((value >> 56) as u8)?;
+    MeshCode::new(level, value & 0x00FF_FFFF_FFFF_FFFF)
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Result<u64> {
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes
+            .get(*pos)
+            .ok_or_else(|| MeshCodeError::InvalidFormat("Unexpected end of data".to_string()))?;
+        *pos += 1;
+
+        let bits = (byte & 0x7F) as u64;
+        if shift == 63 && bits > 1 || shift > 63 {
+            return Err(MeshCodeError::InvalidFormat(
+                "Varint overflows u64".to_string(),
+            ));
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+/// メッシュコードの集合をバイト列に変換する
+///
+/// メッシュコードは昇順に並べ替えて格納します（重複はそのまま保持します）。
+/// 形式はモジュールの説明を参照してください。
+///
+/// # 引数
+/// * `meshes` - メッシュコードのスライス
+///
+/// # 戻り値
+/// 変換したバイト列
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::io::{decode_meshes, encode_meshes};
+/// use jismeshcode::prelude::*;
+///
+/// let meshes = children(MeshCode::from_str("533946").unwrap());
+/// let bytes = encode_meshes(&meshes);
+/// assert!(bytes.len() < meshes.len() * 8);
+/// assert_eq!(decode_meshes(&bytes).unwrap(), meshes);
+/// ```
+pub fn encode_meshes(meshes: &[MeshCode]) -> Vec<u8> {
+    let mut values: Vec<u64> = meshes.iter().map(|&m| pack(m)).collect();
+    values.sort_unstable();
+
+    let mut out = Vec::with_capacity(values.len() * 2 + 10);
+    out.push(FORMAT_VERSION);
+    write_varint(&mut out, values.len() as u64);
+
+    let mut prev = 0;
+    for value in values {
+        write_varint(&mut out, value - prev);
+        prev = value;
+    }
+    out
+}
+
+/// [`encode_meshes`]で変換したバイト列からメッシュコードを復元する
+///
+/// # 引数
+/// * `bytes` - バイト列
+///
+/// # 戻り値
+/// 昇順に並んだメッシュコードのベクター。バージョンが異なる場合、データが途中で
+/// 終わっている場合、余分なデータがある場合は[`MeshCodeError::InvalidFormat`]、
+/// 復元した値が有効なメッシュコードでない場合はそのエラー
+pub fn decode_meshes(bytes: &[u8]) -> Result<Vec<MeshCode>> {
+    let (&version, rest) = bytes
+        .split_first()
+        .ok_or_else(|| MeshCodeError::InvalidFormat("Empty data".to_string()))?;
+    if version != FORMAT_VERSION {
+        return Err(MeshCodeError::InvalidFormat(format!(
+            "Unsupported format version: {version}"
+        )));
+    }
+
+    let mut pos = 0;
+    let count = read_varint(rest, &mut pos)?;
+    // 1件は最低1バイトのため、残りのバイト数を超える個数は不正
+    if count > (rest.len() - pos) as u64 {
+        return Err(MeshCodeError::InvalidFormat(format!(
+            "Count {count} exceeds the remaining data"
+        )));
+    }
+
+    let mut meshes = Vec::with_capacity(count as usize);
+    let mut value: u64 = 0;
+    for _ in 0..count {
+        let delta = read_varint(rest, &mut pos)?;
+        value = value
+            .checked_add(delta)
+            .ok_or_else(|| MeshCodeError::InvalidFormat("Value overflows u64".to_string()))?;
+        meshes.push(unpack(value)?);
+    }
+
+    if pos != rest.len() {
+        return Err(MeshCodeError::InvalidFormat(
+            "Trailing data after the last mesh code".to_string(),
+        ));
+    }
+    Ok(meshes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::coord_to_mesh;
+    use crate::operations::children;
+    use crate::types::Coordinate;
+
+    #[test]
+    fn test_roundtrip_thousands_of_meshes() {
+        let mut meshes = Vec::new();
+        for code in ["533946", "533945", "523960"] {
+            let second = MeshCode::from_str(code).unwrap();
+            for third in children(second) {
+                meshes.extend(children(third));
+            }
+        }
+        meshes.push(MeshCode::from_str("5339").unwrap());
+        let fifth = coord_to_mesh(
+            Coordinate::new(35.6812, 139.7671).unwrap(),
+            MeshLevel::Fifth,
+        )
+        .unwrap();
+        meshes.push(fifth);
+        meshes.push(fifth);
+        assert!(meshes.len() > 1000);
+
+        let bytes = encode_meshes(&meshes);
+        assert!(bytes.len() < meshes.len() * 8);
+        assert_eq!(bytes[0], FORMAT_VERSION);
+
+        let mut expected = meshes.clone();
+        expected.sort();
+        assert_eq!(decode_meshes(&bytes).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_empty_and_invalid_data() {
+        assert_eq!(encode_meshes(&[]), alloc::vec![FORMAT_VERSION, 0]);
+        assert!(decode_meshes(&encode_meshes(&[])).unwrap().is_empty());
+
+        assert!(decode_meshes(&[]).is_err());
+        assert!(decode_meshes(&[2, 0]).is_err());
+
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let bytes = encode_meshes(&[mesh]);
+        assert!(decode_meshes(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(decode_meshes(&trailing).is_err());
+
+        // 存在しないレベル番号
+        let mut invalid = alloc::vec![FORMAT_VERSION, 1];
+        write_varint(&mut invalid, 9u64 << 56);
+        assert_eq!(decode_meshes(&invalid), Err(MeshCodeError::InvalidLevel(9)));
+    }
+
+    #[test]
+    fn test_varint_roundtrip() {
+        for value in [0, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            let mut out = Vec::new();
+            write_varint(&mut out, value);
+            let mut pos = 0;
+            assert_eq!(read_varint(&out, &mut pos).unwrap(), value);
+            assert_eq!(pos, out.len());
+        }
+
+        // 11バイト目まで続くvarintはu64に収まらない
+        let overflow = [0xFF; 10];
+        assert!(read_varint(&overflow, &mut 0).is_err());
+    }
+}
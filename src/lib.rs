@@ -40,8 +40,12 @@ extern crate alloc;
 pub mod convert;
 /// エラー型の定義
 pub mod error;
+/// メッシュコードの集合のバイナリ形式での保存・復元
+pub mod io;
 /// メッシュの操作機能（階層、隣接など）
 pub mod operations;
+/// メッシュと都道府県の簡易的な対応
+pub mod regions;
 /// 空間検索機能
 pub mod spatial;
 /// 基本的な型定義
@@ -49,15 +53,45 @@ pub mod types;
 /// ユーティリティ機能
 pub mod utils;
 
+/// プロパティテスト用の補助関数
+#[cfg(any(test, feature = "testing"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+
 /// よく使う型と関数を一括でインポートするためのprelude
 pub mod prelude;
 
-pub use convert::{coord_to_mesh, mesh_to_bounds, mesh_to_center};
-pub use error::{CoordinateError, MeshCodeError, Result};
-pub use operations::{bounds, center, children, contains, neighbor, neighbors, parent, to_level};
+#[cfg(feature = "std")]
+pub use convert::ConversionCache;
+pub use convert::{
+    coord_from_dms, coord_to_mesh, coord_to_mesh_best, coord_to_mesh_tolerant, describe,
+    mesh_at_offset, mesh_centers, mesh_geodesic_center, mesh_to_bounds, mesh_to_center,
+    postal_centroid_to_mesh, snap_to_mesh_center, try_mesh_to_bounds, MeshDescription,
+    ToCoordinate, ToMeshCode,
+};
+pub use error::{CoordinateError, MeshCodeError, NeighborError, Result};
+pub use operations::{
+    ancestors, bounds, center, children, children_iter, contains, dissolve, divergence_level,
+    find_nearest_where, grid_around, neighbor, neighbor_within_bbox, neighbors, neighbors_array,
+    neighbors_within_bbox, parent, ring, to_level, try_bounds, try_children, try_neighbor,
+    RingWalker,
+};
+#[cfg(feature = "rayon")]
+pub use spatial::mesh_codes_in_radius_par;
 pub use spatial::{
-    mesh_codes_in_bbox, mesh_codes_in_radius, mesh_codes_in_radius_from_mesh, MeshCodeIterator,
-    MeshCodeRadiusIterator,
+    bbox_grid_dimensions, bbox_mesh_grid, boundary_meshes_of_bbox, mesh_circle_overlap_fraction,
+    mesh_codes_along_geodesic, mesh_codes_covering_polygon, mesh_codes_fully_inside_polygon,
+    mesh_codes_in_bbox, mesh_codes_in_bbox_grid, mesh_codes_in_bbox_with_centers,
+    mesh_codes_in_bboxes, mesh_codes_in_polygon, mesh_codes_in_radius,
+    mesh_codes_in_radius_from_mesh, mesh_codes_in_radius_from_mesh_overlap, radius_coverage_ratio,
+    smallest_containing_mesh, MeshBitSet, MeshCodeIterator, MeshCodeRadiusIterator,
+};
+pub use types::{
+    japan_mesh_bounds, BoundingBox, Coordinate, Direction, FourthMesh, LabeledMeshCode, MeshCode,
+    MeshCodeNumeric, MeshCodeStr, MeshLevel,
+};
+pub use utils::distance::{
+    calculate_bbox_offsets, distance_to_mesh_edge, haversine_distance,
+    haversine_distance_precomputed, haversine_distance_with_radius, haversine_precompute,
+    mesh_bearing,
 };
-pub use types::{BoundingBox, Coordinate, Direction, MeshCode, MeshLevel};
-pub use utils::distance::haversine_distance;
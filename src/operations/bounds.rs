@@ -1,10 +1,37 @@
 use crate::convert::{mesh_to_bounds, mesh_to_center};
+use crate::error::CoordResult;
 use crate::types::{BoundingBox, Coordinate, MeshCode};
 
 pub fn bounds(mesh: MeshCode) -> BoundingBox {
     mesh_to_bounds(mesh)
 }
 
+/// 日本の範囲内であることを検証したメッシュの境界を取得する
+///
+/// [`mesh_to_bounds`]は角の座標を検証せずに作成するため、日本の範囲の端にある
+/// メッシュでは北東端が範囲（北緯46度、東経154度）を超えることがあります。
+/// この関数は南西端・北東端を[`Coordinate::new`]で検証し、範囲外の場合は
+/// エラーを返します。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let tokyo = MeshCode::from_str("53394611").unwrap();
+/// assert!(try_bounds(tokyo).is_ok());
+///
+/// // 北緯46度〜46度40分の1次メッシュは北東端が範囲外
+/// let north = MeshCode::from_str("6939").unwrap();
+/// assert!(try_bounds(north).is_err());
+/// ```
+pub fn try_bounds(mesh: MeshCode) -> CoordResult<BoundingBox> {
+    let bbox = mesh_to_bounds(mesh);
+    let sw = Coordinate::new(bbox.min_lat(), bbox.min_lon())?;
+    let ne = Coordinate::new(bbox.max_lat(), bbox.max_lon())?;
+    Ok(BoundingBox::new(sw, ne))
+}
+
 pub fn center(mesh: MeshCode) -> Coordinate {
     mesh_to_center(mesh)
 }
@@ -26,6 +53,24 @@ mod tests {
         assert!(bbox.max_lat() > bbox.min_lat());
     }
 
+    #[test]
+    fn test_try_bounds_northern_edge() {
+        // 北緯46度ちょうどの座標は有効だが、それを含む1次メッシュの北東端は範囲外
+        let coord = Coordinate::new(46.0, 139.5).unwrap();
+        let mesh = crate::convert::coord_to_mesh(coord, crate::types::MeshLevel::First).unwrap();
+        assert_eq!(mesh.as_string(), "6939");
+
+        let unchecked = bounds(mesh);
+        assert!(unchecked.max_lat() > 46.0);
+        assert_eq!(
+            try_bounds(mesh),
+            Err(crate::error::CoordinateError::OutOfJapanRange)
+        );
+
+        let tokyo = MeshCode::from_str("53394611").unwrap();
+        assert_eq!(try_bounds(tokyo).unwrap(), bounds(tokyo));
+    }
+
     #[test]
     fn test_center() {
         let mesh = MeshCode::from_str("53393599").unwrap();
@@ -1,5 +1,6 @@
-use crate::error::Result;
+use crate::error::{MeshCodeError, Result};
 use crate::types::{MeshCode, MeshLevel};
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::format;
 use alloc::string::ToString;
 use alloc::vec::Vec;
@@ -35,6 +36,77 @@ pub fn parent(mesh: MeshCode) -> Option<MeshCode> {
     MeshCode::from_str(parent_code_str).ok()
 }
 
+/// メッシュコードの祖先メッシュをすべて取得する
+///
+/// [`parent`]を繰り返し適用し、直近の親から1次メッシュまでの順に返します。
+/// 例えば5次メッシュでは[3次, 2次, 1次]、8分の1メッシュでは
+/// [4分の1, 2分の1, 3次, 2次, 1次]になります。1次メッシュでは空になります。
+///
+/// # 引数
+/// * `mesh` - 対象のメッシュコード
+///
+/// # 戻り値
+/// 祖先メッシュコードのベクター（直近の親から順）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("533946112").unwrap();
+/// let chain: Vec<String> = ancestors(mesh).iter().map(|m| m.as_string()).collect();
+/// assert_eq!(chain, ["53394611", "533946", "5339"]);
+/// ```
+pub fn ancestors(mesh: MeshCode) -> Vec<MeshCode> {
+    core::iter::successors(parent(mesh), |&m| parent(m)).collect()
+}
+
+/// 2つのメッシュの祖先が初めて異なるレベルを返す
+///
+/// 両方のメッシュの系統（自身と[`ancestors`]）を1次メッシュから順に比べ、
+/// 最初に異なるメッシュになるレベルを返します。これは共通の祖先のうち
+/// 最も細かいものより1段階細かいレベルで、隣接するメッシュがどのレベルの境界で
+/// 分かれているかを表します。同じメッシュの場合や一方が他方の祖先の場合、
+/// 系統が分かれる前に比較できるレベルがなくなる場合（例: 同じ3次メッシュ内の
+/// 5次メッシュと2分の1メッシュ）は`None`を返します。
+///
+/// # 引数
+/// * `a` - 比較するメッシュコード
+/// * `b` - 比較するメッシュコード
+///
+/// # 戻り値
+/// 祖先が初めて異なるレベル、または該当しない場合は`None`
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let a = MeshCode::from_str("53394611").unwrap();
+/// let b = MeshCode::from_str("53394612").unwrap();
+/// assert_eq!(divergence_level(a, b), Some(MeshLevel::Third));
+///
+/// // 2次メッシュの境界をまたぐ隣接メッシュ
+/// let c = MeshCode::from_str("53394700").unwrap();
+/// let d = MeshCode::from_str("53394619").unwrap();
+/// assert_eq!(divergence_level(c, d), Some(MeshLevel::Second));
+/// ```
+pub fn divergence_level(a: MeshCode, b: MeshCode) -> Option<MeshLevel> {
+    let lineage = |mesh: MeshCode| {
+        let mut chain = ancestors(mesh);
+        chain.reverse();
+        chain.push(mesh);
+        chain
+    };
+
+    lineage(a)
+        .into_iter()
+        .zip(lineage(b))
+        .take_while(|(x, y)| x.level() == y.level())
+        .find(|(x, y)| x != y)
+        .map(|(x, _)| x.level())
+}
+
 /// メッシュコードの子メッシュをすべて取得する
 ///
 /// 1次メッシュは64個の2次メッシュを、2次メッシュは100個の3次メッシュを、
@@ -59,51 +131,139 @@ pub fn parent(mesh: MeshCode) -> Option<MeshCode> {
 /// assert_eq!(children_list.len(), 100); // 2次メッシュは100個の3次メッシュを持つ
 /// ```
 pub fn children(mesh: MeshCode) -> Vec<MeshCode> {
-    let level = mesh.level();
-    let code_str = mesh.as_string();
+    children_iter(mesh).collect()
+}
 
-    match level {
-        MeshLevel::First => {
-            let mut result = Vec::with_capacity(64);
-            for t in 0..8 {
-                for u in 0..8 {
-                    let child_str = format!("{code_str}{t}{u}");
-                    if let Ok(child) = MeshCode::from_str(&child_str) {
-                        result.push(child);
-                    }
-                }
-            }
-            result
-        }
-        MeshLevel::Second => {
-            let mut result = Vec::with_capacity(100);
-            for v in 0..10 {
-                for w in 0..10 {
-                    let child_str = format!("{code_str}{v}{w}");
-                    if let Ok(child) = MeshCode::from_str(&child_str) {
-                        result.push(child);
-                    }
-                }
-            }
-            result
-        }
-        MeshLevel::Third => subdivision_children(&code_str, MeshLevel::FourthHalf),
-        MeshLevel::FourthHalf => subdivision_children(&code_str, MeshLevel::FourthQuarter),
-        MeshLevel::FourthQuarter => subdivision_children(&code_str, MeshLevel::FourthEighth),
-        MeshLevel::FourthEighth | MeshLevel::Fifth => Vec::new(),
+/// メッシュコードの子メッシュをすべて取得する（子を持たないレベルはエラー）
+///
+/// [`children`]と同じ子メッシュを返しますが、8分の1メッシュと5次メッシュのように
+/// 子が定義されていないレベルでは空のベクターの代わりに
+/// [`MeshCodeError::InvalidFormat`]を返します。空の結果を見落とさずに
+/// 扱いたい場合に使用します。
+///
+/// # 引数
+/// * `mesh` - 対象のメッシュコード
+///
+/// # 戻り値
+/// 子メッシュコードのベクター、または子を持たないレベルの場合はエラー
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let third = MeshCode::from_str("53394611").unwrap();
+/// assert_eq!(try_children(third).unwrap().len(), 4);
+///
+/// let fifth = MeshCode::parse("5339461111", Some(MeshLevel::Fifth)).unwrap();
+/// assert!(try_children(fifth).is_err());
+/// ```
+pub fn try_children(mesh: MeshCode) -> Result<Vec<MeshCode>> {
+    match mesh.level() {
+        MeshLevel::FourthEighth | MeshLevel::Fifth => Err(MeshCodeError::InvalidFormat(format!(
+            "{} has no children",
+            mesh.level().label()
+        ))),
+        _ => Ok(children(mesh)),
     }
 }
 
-/// 分割地域メッシュの子（番号1〜4を付加したコード）を生成する
-fn subdivision_children(code_str: &str, child_level: MeshLevel) -> Vec<MeshCode> {
-    let mut result = Vec::with_capacity(4);
-    for i in 1..=4 {
-        let child_code = format!("{code_str}{i}").parse::<u64>().unwrap();
-        if let Ok(child) = MeshCode::new(child_level, child_code) {
-            result.push(child);
+/// メッシュコードの子メッシュを順に生成するイテレータを返す
+///
+/// [`children`]と同じ子メッシュを同じ順序で返しますが、`Vec`を確保せずに
+/// 必要になった時点で1つずつ生成します。`size_hint`は残りの個数を正確に返します。
+///
+/// # 引数
+/// * `mesh` - 対象のメッシュコード
+///
+/// # 戻り値
+/// 子メッシュコードのイテレータ
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("5339").unwrap();
+/// let mut iter = children_iter(mesh);
+/// assert_eq!(iter.len(), 64);
+/// assert_eq!(iter.next().unwrap().as_string(), "533900");
+/// ```
+pub fn children_iter(mesh: MeshCode) -> impl ExactSizeIterator<Item = MeshCode> {
+    // (子のレベル, 子の個数, 親コードに掛ける倍率)
+    let (child_level, count, multiplier) = match mesh.level() {
+        MeshLevel::First => (MeshLevel::Second, 64, 100),
+        MeshLevel::Second => (MeshLevel::Third, 100, 100),
+        MeshLevel::Third => (MeshLevel::FourthHalf, 4, 10),
+        MeshLevel::FourthHalf => (MeshLevel::FourthQuarter, 4, 10),
+        MeshLevel::FourthQuarter => (MeshLevel::FourthEighth, 4, 10),
+        MeshLevel::FourthEighth | MeshLevel::Fifth => (mesh.level(), 0, 0),
+    };
+    let base = mesh.code() * multiplier;
+
+    (0..count).map(move |i: u32| {
+        let suffix = match child_level {
+            // 2次メッシュの緯度・経度番号はそれぞれ0〜7
+            MeshLevel::Second => (i / 8) * 10 + i % 8,
+            MeshLevel::Third => i,
+            // 分割地域メッシュの番号は1〜4
+            _ => i + 1,
+        };
+        // 有効なメッシュの末尾に有効な番号を付けたコードは常に有効
+        MeshCode::new_unchecked(child_level, base + suffix as u64)
+    })
+}
+
+/// 兄弟メッシュがすべて揃っている場合に親メッシュへまとめる
+///
+/// 同じ親を持ち同じレベルの子メッシュがすべて含まれている場合
+/// （2次メッシュは64個、3次メッシュ・5次メッシュは100個、分割地域メッシュは4個で
+/// 親を覆います）、それらを親メッシュ1つに置き換えます。置き換えた結果さらに兄弟が揃う場合は
+/// 繰り返しまとめます。重複したメッシュは1つにまとめられます。
+///
+/// # 引数
+/// * `meshes` - メッシュコードのスライス
+///
+/// # 戻り値
+/// まとめた後のメッシュコードのベクター（昇順）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let third = MeshCode::from_str("53394611").unwrap();
+/// let halves = children(third);
+/// assert_eq!(dissolve(&halves), vec![third]);
+/// ```
+pub fn dissolve(meshes: &[MeshCode]) -> Vec<MeshCode> {
+    let mut set: BTreeSet<MeshCode> = meshes.iter().copied().collect();
+
+    loop {
+        // 親と子のレベルごとに含まれる子の数を数える
+        let mut counts: BTreeMap<(MeshCode, MeshLevel), u64> = BTreeMap::new();
+        for &mesh in &set {
+            if let Some(p) = parent(mesh) {
+                *counts.entry((p, mesh.level())).or_insert(0) += 1;
+            }
+        }
+
+        let complete: BTreeSet<(MeshCode, MeshLevel)> = counts
+            .into_iter()
+            .filter(|&((p, level), count)| {
+                MeshLevel::subdivision_count(p.level(), level) == Some(count)
+            })
+            .map(|(key, _)| key)
+            .collect();
+        if complete.is_empty() {
+            break;
         }
+
+        set.retain(|&mesh| parent(mesh).is_none_or(|p| !complete.contains(&(p, mesh.level()))));
+        set.extend(complete.iter().map(|&(p, _)| p));
     }
-    result
+
+    set.into_iter().collect()
 }
 
 /// メッシュコードを指定レベルへ変換する
@@ -128,7 +288,7 @@ pub fn to_level(mesh: MeshCode, target_level: MeshLevel) -> Result<MeshCode> {
     };
 
     if !is_ancestor {
-        return Err(crate::error::MeshCodeError::InvalidFormat(
+        return Err(MeshCodeError::InvalidFormat(
             "Target level is not an ancestor of the current level".to_string(),
         ));
     }
@@ -181,4 +341,138 @@ mod tests {
         let first = to_level(mesh, MeshLevel::First).unwrap();
         assert_eq!(first.as_string(), "5339");
     }
+
+    #[test]
+    fn test_dissolve_second_children() {
+        let second = MeshCode::from_str("533946").unwrap();
+        assert_eq!(dissolve(&children(second)), alloc::vec![second]);
+    }
+
+    #[test]
+    fn test_dissolve_recursive_and_partial() {
+        let third = MeshCode::from_str("53394611").unwrap();
+        let half = MeshCode::from_str("533946112").unwrap();
+
+        // 2分の1の子（4分の1）4個と、残りの2分の1 3個で3次メッシュになる
+        let mut meshes = children(half);
+        meshes.extend(children(third).into_iter().filter(|&m| m != half));
+        meshes.push(meshes[0]);
+        assert_eq!(dissolve(&meshes), alloc::vec![third]);
+
+        // 揃っていない兄弟はそのまま残る
+        let mut partial = children(third);
+        partial.pop();
+        let other = MeshCode::from_str("53394612").unwrap();
+        partial.push(other);
+        let mut expected = partial.clone();
+        expected.sort();
+        assert_eq!(dissolve(&partial), expected);
+
+        assert!(dissolve(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let fifth = MeshCode::parse("5339461197", Some(MeshLevel::Fifth)).unwrap();
+        let chain = ancestors(fifth);
+        let codes: Vec<_> = chain.iter().map(|m| m.as_string()).collect();
+        assert_eq!(codes, ["53394611", "533946", "5339"]);
+        assert_eq!(chain.last().unwrap().level(), MeshLevel::First);
+
+        let eighth = MeshCode::from_str("53394611234").unwrap();
+        let levels: Vec<_> = ancestors(eighth).iter().map(|m| m.level()).collect();
+        assert_eq!(
+            levels,
+            [
+                MeshLevel::FourthQuarter,
+                MeshLevel::FourthHalf,
+                MeshLevel::Third,
+                MeshLevel::Second,
+                MeshLevel::First
+            ]
+        );
+
+        assert!(ancestors(MeshCode::from_str("5339").unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_children_iter() {
+        // (親, 子の個数, 最初の子, 最後の子)
+        for (code, count, first, last) in [
+            ("5339", 64, "533900", "533977"),
+            ("0001", 64, "000100", "000177"),
+            ("533946", 100, "53394600", "53394699"),
+            ("53394611", 4, "533946111", "533946114"),
+            ("533946113", 4, "5339461131", "5339461134"),
+            ("5339461134", 4, "53394611341", "53394611344"),
+        ] {
+            let mesh = MeshCode::from_str(code).unwrap();
+            let iter = children_iter(mesh);
+            assert_eq!(iter.size_hint(), (count, Some(count)));
+
+            let codes: Vec<_> = iter.map(|m| m.as_string()).collect();
+            assert_eq!(codes.len(), count);
+            assert_eq!(codes.first().unwrap(), first);
+            assert_eq!(codes.last().unwrap(), last);
+            assert!(codes.windows(2).all(|w| w[0] < w[1]), "{code}");
+        }
+
+        // 2次メッシュは行ごとに経度番号0〜7を並べ、8・9は含まない
+        let second: Vec<_> = children_iter(MeshCode::from_str("5339").unwrap())
+            .map(|m| m.as_string())
+            .collect();
+        assert_eq!(&second[6..10], ["533906", "533907", "533910", "533911"]);
+
+        let third: Vec<_> = children_iter(MeshCode::from_str("53394611").unwrap())
+            .map(|m| m.as_string())
+            .collect();
+        assert_eq!(third, ["533946111", "533946112", "533946113", "533946114"]);
+
+        let eighth = MeshCode::from_str("53394611342").unwrap();
+        assert_eq!(children_iter(eighth).len(), 0);
+    }
+
+    #[test]
+    fn test_divergence_level() {
+        let a = MeshCode::from_str("53394611").unwrap();
+        let b = MeshCode::from_str("53394699").unwrap();
+        assert_eq!(parent(a), parent(b));
+        assert_eq!(divergence_level(a, b), Some(MeshLevel::Third));
+
+        let c = MeshCode::from_str("53394700").unwrap();
+        assert_eq!(divergence_level(a, c), Some(MeshLevel::Second));
+
+        let d = MeshCode::from_str("54394611").unwrap();
+        assert_eq!(divergence_level(a, d), Some(MeshLevel::First));
+
+        // レベルが異なっていても系統を比較できる
+        let e = MeshCode::from_str("5339461234").unwrap();
+        assert_eq!(divergence_level(a, e), Some(MeshLevel::Third));
+        let f = MeshCode::from_str("533946113").unwrap();
+        let g = MeshCode::from_str("5339461124").unwrap();
+        assert_eq!(divergence_level(f, g), Some(MeshLevel::FourthHalf));
+
+        assert_eq!(divergence_level(a, a), None);
+        assert_eq!(divergence_level(a, f), None);
+        let fifth = MeshCode::parse("5339461199", Some(MeshLevel::Fifth)).unwrap();
+        assert_eq!(divergence_level(f, fifth), None);
+    }
+
+    #[test]
+    fn test_try_children() {
+        let fifth = MeshCode::parse("5339461111", Some(MeshLevel::Fifth)).unwrap();
+        assert!(matches!(
+            try_children(fifth),
+            Err(MeshCodeError::InvalidFormat(_))
+        ));
+        let eighth = MeshCode::from_str("53394611342").unwrap();
+        assert!(try_children(eighth).is_err());
+
+        for code in ["5339", "533946", "53394611", "533946113", "5339461134"] {
+            let mesh = MeshCode::from_str(code).unwrap();
+            let result = try_children(mesh).unwrap();
+            assert!(!result.is_empty());
+            assert_eq!(result, children(mesh));
+        }
+    }
 }
@@ -1,7 +1,14 @@
 mod bounds;
 mod hierarchy;
 mod neighbors;
+pub mod set;
+pub mod stats;
 
-pub use bounds::{bounds, center, contains};
-pub use hierarchy::{children, parent, to_level};
-pub use neighbors::{neighbor, neighbors};
+pub use bounds::{bounds, center, contains, try_bounds};
+pub use hierarchy::{
+    ancestors, children, children_iter, dissolve, divergence_level, parent, to_level, try_children,
+};
+pub use neighbors::{
+    find_nearest_where, grid_around, neighbor, neighbor_within_bbox, neighbors, neighbors_array,
+    neighbors_within_bbox, ring, try_neighbor, RingWalker,
+};
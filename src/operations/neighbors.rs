@@ -1,5 +1,6 @@
 use crate::convert::{coord_to_mesh, mesh_to_center};
-use crate::types::{Direction, MeshCode};
+use crate::error::NeighborError;
+use crate::types::{BoundingBox, Direction, MeshCode, MeshLevel, JAPAN_BOUNDS};
 use alloc::vec::Vec;
 
 /// 指定された方向の隣接メッシュを取得する
@@ -21,23 +22,105 @@ use alloc::vec::Vec;
 /// assert!(north.is_some());
 /// ```
 pub fn neighbor(mesh: MeshCode, direction: Direction) -> Option<MeshCode> {
+    let (dx, dy) = direction.offset();
+    offset_mesh(mesh, dx, dy)
+}
+
+/// 指定された方向の隣接メッシュを取得する（取得できない理由を返す）
+///
+/// [`neighbor`]と同じ隣接メッシュを返しますが、取得できない場合に、
+/// 日本の範囲外であるのか（[`NeighborError::OutOfRange`]）、
+/// メッシュコードへの変換に失敗したのか（[`NeighborError::ConversionFailed`]）を
+/// 区別できます。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("6841").unwrap(); // 北緯45度20分〜46度
+/// assert_eq!(try_neighbor(mesh, Direction::North), Err(NeighborError::OutOfRange));
+/// assert!(try_neighbor(mesh, Direction::South).is_ok());
+/// ```
+pub fn try_neighbor(
+    mesh: MeshCode,
+    direction: Direction,
+) -> core::result::Result<MeshCode, NeighborError> {
+    let (dx, dy) = direction.offset();
+    try_offset_mesh(mesh, dx, dy)
+}
+
+/// 東西に`dx`個、南北に`dy`個ずらした位置にある同じレベルのメッシュを取得する
+///
+/// メッシュの中心座標をメッシュサイズ単位でずらして変換します。分割地域メッシュは
+/// 分割番号の並びから移動先を求めます（[`try_offset_subdivision`]）。
+/// 移動先が日本の範囲外の場合はNoneを返します。
+fn offset_mesh(mesh: MeshCode, dx: i32, dy: i32) -> Option<MeshCode> {
+    try_offset_mesh(mesh, dx, dy).ok()
+}
+
+fn try_offset_mesh(
+    mesh: MeshCode,
+    dx: i32,
+    dy: i32,
+) -> core::result::Result<MeshCode, NeighborError> {
+    if let Some(result) = try_offset_subdivision(mesh, dx, dy) {
+        return result;
+    }
+
     let center = mesh_to_center(mesh);
     let level = mesh.level();
 
     let lat_size = level.lat_size_degrees();
     let lon_size = level.lon_size_degrees();
 
-    let (dx, dy) = direction.offset();
-
     let new_lat = center.lat() + dy as f64 * lat_size;
     let new_lon = center.lon() + dx as f64 * lon_size;
 
-    if !(20.0..=46.0).contains(&new_lat) || !(122.0..=154.0).contains(&new_lon) {
+    let new_coord = crate::types::Coordinate::new_unchecked(new_lat, new_lon);
+    if !JAPAN_BOUNDS.contains(new_coord) {
+        return Err(NeighborError::OutOfRange);
+    }
+
+    coord_to_mesh(new_coord, level).map_err(NeighborError::ConversionFailed)
+}
+
+/// 分割地域メッシュを分割番号の並びに沿って`dx`・`dy`個ずらす
+///
+/// 分割番号は親メッシュの中で南西=1、南東=2、北西=3、北東=4の2×2に並びます。
+/// 例えば北西（3）から東へ1つ移動すると同じ親の北東（4）に、北東（4）から東へ
+/// 1つ移動すると東隣の親メッシュの北西（3）になります。親メッシュをまたぐ分は
+/// 親メッシュを移動させ、範囲外の判定も親メッシュの移動で行います。
+/// 分割地域メッシュ以外のレベルではNoneを返します。
+fn try_offset_subdivision(
+    mesh: MeshCode,
+    dx: i32,
+    dy: i32,
+) -> Option<core::result::Result<MeshCode, NeighborError>> {
+    let level = mesh.level();
+    if !matches!(
+        level,
+        MeshLevel::FourthHalf | MeshLevel::FourthQuarter | MeshLevel::FourthEighth
+    ) {
         return None;
     }
 
-    let new_coord = crate::types::Coordinate::new_unchecked(new_lat, new_lon);
-    coord_to_mesh(new_coord, level).ok()
+    let code = mesh.code();
+    let index = (code % 10) as i32 - 1;
+    // 飽和しても親メッシュの移動量は日本の範囲を大きく超えるため、範囲外と判定される
+    let row = dy.saturating_add(index / 2);
+    let col = dx.saturating_add(index % 2);
+
+    let result = MeshCode::new(level.parent()?, code / 10)
+        .map_err(NeighborError::ConversionFailed)
+        .and_then(|parent| try_offset_mesh(parent, col.div_euclid(2), row.div_euclid(2)))
+        .and_then(|parent| {
+            let digit = row.rem_euclid(2) * 2 + col.rem_euclid(2) + 1;
+            parent
+                .child(digit as u32)
+                .map_err(NeighborError::ConversionFailed)
+        });
+    Some(result)
 }
 
 /// すべての方向の隣接メッシュを取得する
@@ -67,6 +150,271 @@ pub fn neighbors(mesh: MeshCode) -> Vec<MeshCode> {
         .collect()
 }
 
+/// すべての方向の隣接メッシュを方向順の固定長配列で取得する
+///
+/// [`Direction::ALL`]と同じ順序で並んだ配列を返し、範囲外の方向は`None`になります。
+/// [`neighbors`]と異なり位置の情報が失われないため、`result[Direction::East.index()]`
+/// のように方向で添字を指定できます。
+///
+/// # 引数
+/// * `mesh` - 対象のメッシュコード
+///
+/// # 戻り値
+/// 方向ごとの隣接メッシュコードの配列
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let around = neighbors_array(mesh);
+/// assert_eq!(around[Direction::East.index()].unwrap().as_string(), "53394612");
+/// ```
+pub fn neighbors_array(mesh: MeshCode) -> [Option<MeshCode>; 8] {
+    Direction::ALL.map(|dir| neighbor(mesh, dir))
+}
+
+/// 境界ボックス内にある指定方向の隣接メッシュを取得する
+///
+/// [`neighbor`]で得た隣接メッシュのうち、中心座標が`bbox`内（境界上を含む）に
+/// あるものだけを返します。タイルごとの並列処理で、タイルをまたぐ隣接関係を
+/// 除外する用途に使います。
+///
+/// # 引数
+/// * `mesh` - 対象のメッシュコード
+/// * `direction` - 方向
+/// * `bbox` - 隣接メッシュの中心が含まれるべき範囲
+///
+/// # 戻り値
+/// 隣接メッシュコード、または範囲外の場合はNone
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let bbox = mesh_to_bounds(mesh);
+/// // 自身の範囲内には隣接メッシュの中心は含まれない
+/// assert_eq!(neighbor_within_bbox(mesh, Direction::North, bbox), None);
+/// ```
+pub fn neighbor_within_bbox(
+    mesh: MeshCode,
+    direction: Direction,
+    bbox: BoundingBox,
+) -> Option<MeshCode> {
+    neighbor(mesh, direction).filter(|&n| bbox.contains(mesh_to_center(n)))
+}
+
+/// 境界ボックス内にあるすべての方向の隣接メッシュを取得する
+///
+/// [`neighbors`]のうち、中心座標が`bbox`内にある隣接メッシュだけを返します。
+///
+/// # 引数
+/// * `mesh` - 対象のメッシュコード
+/// * `bbox` - 隣接メッシュの中心が含まれるべき範囲
+///
+/// # 戻り値
+/// 隣接メッシュコードのベクター（最大8個）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// // 2次メッシュの南西端にある3次メッシュでは、2次メッシュ外の隣接メッシュが除かれる
+/// let mesh = MeshCode::from_str("53394600").unwrap();
+/// let bbox = mesh_to_bounds(parent(mesh).unwrap());
+/// assert!(neighbors_within_bbox(mesh, bbox).len() < neighbors(mesh).len());
+/// ```
+pub fn neighbors_within_bbox(mesh: MeshCode, bbox: BoundingBox) -> Vec<MeshCode> {
+    Direction::ALL
+        .iter()
+        .filter_map(|&dir| neighbor_within_bbox(mesh, dir, bbox))
+        .collect()
+}
+
+/// 指定メッシュからちょうど`k`個離れたリング上のメッシュを取得する
+///
+/// 中心メッシュを囲む正方形の外周（東西・南北方向の距離の大きい方が`k`の位置）に
+/// あるメッシュを、南の行から北の行へ、各行は西から東の順に返します。
+/// `k = 0`の場合は中心メッシュのみ、`k = 1`の場合は[`neighbors`]と同じ8個の
+/// メッシュになります。範囲外のメッシュは結果に含まれません。
+///
+/// # 引数
+/// * `mesh` - 中心のメッシュコード
+/// * `k` - リングの番号（中心からの距離、メッシュ数単位）
+///
+/// # 戻り値
+/// リング上のメッシュコードのベクター（最大`8k`個）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// assert_eq!(ring(mesh, 0), vec![mesh]);
+/// assert_eq!(ring(mesh, 2).len(), 16);
+/// ```
+pub fn ring(mesh: MeshCode, k: u32) -> Vec<MeshCode> {
+    if k == 0 {
+        return alloc::vec![mesh];
+    }
+
+    // i32に収まらない距離のメッシュは日本の範囲外にしかない
+    let Ok(k) = i32::try_from(k) else {
+        return Vec::new();
+    };
+
+    let mut result = Vec::new();
+    let mut push = |dx: i32, dy: i32| {
+        if let Some(m) = offset_mesh(mesh, dx, dy) {
+            result.push(m);
+        }
+    };
+
+    // 南の行、東西の辺（南から北へ）、北の行の順にたどる
+    for dx in -k..=k {
+        push(dx, -k);
+    }
+    for dy in (-k + 1)..k {
+        push(-k, dy);
+        push(k, dy);
+    }
+    for dx in -k..=k {
+        push(dx, k);
+    }
+    result
+}
+
+/// 中心メッシュの周囲のメッシュを2次元の格子として取得する
+///
+/// 中心から南北に`rows`個、東西に`cols`個ずつ広げた`(2 * rows + 1)`行
+/// `(2 * cols + 1)`列の格子を返します。外側の`Vec`は北の行から南の行へ、
+/// 各行は西から東の順に並び、中心メッシュは`grid[rows][cols]`です。
+/// 各セルは格子番号の整数演算で求めるため、隣接メッシュを繰り返したどる場合と
+/// 異なり、日本の範囲外のセルは中心を繰り返さず`None`になります。
+/// `rows`・`cols`が負の場合は0として扱います。
+///
+/// # 引数
+/// * `center` - 中心のメッシュコード
+/// * `rows` - 中心から南北それぞれに広げる行数
+/// * `cols` - 中心から東西それぞれに広げる列数
+///
+/// # 戻り値
+/// メッシュコードの2次元格子（範囲外のセルは`None`）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let grid = grid_around(mesh, 1, 1);
+/// assert_eq!(grid.len(), 3);
+/// assert_eq!(grid[1][1], Some(mesh));
+/// assert_eq!(grid[0][1].unwrap().as_string(), "53394621");
+/// ```
+pub fn grid_around(center: MeshCode, rows: i32, cols: i32) -> Vec<Vec<Option<MeshCode>>> {
+    let rows = rows.max(0);
+    let cols = cols.max(0);
+    (-rows..=rows)
+        .rev()
+        .map(|dy| {
+            (-cols..=cols)
+                .map(|dx| offset_mesh(center, dx, dy))
+                .collect()
+        })
+        .collect()
+}
+
+/// 中心メッシュから外側へリング順に探索し、条件を満たす最初のメッシュを返す
+///
+/// 中心メッシュ（リング0）から始めて[`ring`]を1つずつ外側へ広げ、各リング内は
+/// [`ring`]と同じ順序で`pred`を評価します。条件を満たすメッシュが見つかった時点で
+/// 探索を打ち切るため、「データのある最寄りのメッシュ」を探す用途に使えます。
+///
+/// # 引数
+/// * `center` - 探索の中心となるメッシュコード
+/// * `pred` - メッシュが条件を満たすかを判定する関数
+/// * `max_ring` - 探索する最大のリング番号（0の場合は中心のみ）
+///
+/// # 戻り値
+/// 条件を満たす最初のメッシュコード。`max_ring`以内に見つからない場合は`None`
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let target = MeshCode::from_str("53394633").unwrap();
+///
+/// assert_eq!(find_nearest_where(mesh, |m| m == target, 2), Some(target));
+/// assert_eq!(find_nearest_where(mesh, |m| m == target, 1), None);
+/// ```
+pub fn find_nearest_where(
+    center: MeshCode,
+    pred: impl Fn(MeshCode) -> bool,
+    max_ring: u32,
+) -> Option<MeshCode> {
+    (0..=max_ring).find_map(|k| ring(center, k).into_iter().find(|&m| pred(m)))
+}
+
+/// 中心メッシュから外側へ1リングずつ広げていくウォーカー
+///
+/// 選択範囲を1リングずつ拡大するUIのように、状態を保ちながら
+/// [`ring`]を順に取得する用途に使います。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let mut walker = RingWalker::new(mesh);
+/// assert_eq!(walker.step().len(), 8);
+/// assert_eq!(walker.step().len(), 16);
+///
+/// walker.reset();
+/// assert_eq!(walker.step().len(), 8);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingWalker {
+    center: MeshCode,
+    ring: u32,
+}
+
+impl RingWalker {
+    /// 指定メッシュを中心とする新しいウォーカーを作成する
+    pub fn new(center: MeshCode) -> Self {
+        RingWalker { center, ring: 0 }
+    }
+
+    /// 中心のメッシュコードを返す
+    pub fn center(&self) -> MeshCode {
+        self.center
+    }
+
+    /// 直前に返したリングの番号を返す（未開始またはリセット直後は0）
+    pub fn current_ring(&self) -> u32 {
+        self.ring
+    }
+
+    /// 次のリングのメッシュを返し、リング番号を1つ進める
+    pub fn step(&mut self) -> Vec<MeshCode> {
+        self.ring += 1;
+        ring(self.center, self.ring)
+    }
+
+    /// リング番号を0に戻す
+    pub fn reset(&mut self) {
+        self.ring = 0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,6 +426,44 @@ mod tests {
         assert!(north.is_some());
     }
 
+    #[test]
+    fn test_try_neighbor_out_of_range() {
+        let coord = crate::types::Coordinate::new(45.999, 141.5).unwrap();
+        let mesh = coord_to_mesh(coord, crate::types::MeshLevel::Third).unwrap();
+
+        assert_eq!(
+            try_neighbor(mesh, Direction::North),
+            Err(NeighborError::OutOfRange)
+        );
+        assert_eq!(
+            try_neighbor(mesh, Direction::NorthEast),
+            Err(NeighborError::OutOfRange)
+        );
+        assert_eq!(
+            try_neighbor(mesh, Direction::South).ok(),
+            neighbor(mesh, Direction::South)
+        );
+    }
+
+    #[test]
+    fn test_neighbors_within_bbox_at_edge() {
+        // 2次メッシュ533946の南西端にある3次メッシュ
+        let mesh = MeshCode::from_str("53394600").unwrap();
+        let bbox = crate::convert::mesh_to_bounds(MeshCode::from_str("533946").unwrap());
+
+        let within = neighbors_within_bbox(mesh, bbox);
+        assert_eq!(within.len(), 3);
+        assert!(within.len() < neighbors(mesh).len());
+        for n in &within {
+            assert!(bbox.contains(mesh_to_center(*n)));
+        }
+        assert_eq!(neighbor_within_bbox(mesh, Direction::West, bbox), None);
+        assert_eq!(
+            neighbor_within_bbox(mesh, Direction::NorthEast, bbox),
+            neighbor(mesh, Direction::NorthEast)
+        );
+    }
+
     #[test]
     fn test_neighbors() {
         let mesh = MeshCode::from_str("53393599").unwrap();
@@ -85,4 +471,225 @@ mod tests {
         assert!(all_neighbors.len() <= 8);
         assert!(!all_neighbors.is_empty());
     }
+
+    #[test]
+    fn test_ring_one_matches_neighbors() {
+        let mesh = MeshCode::from_str("53393599").unwrap();
+        let mut ring1 = ring(mesh, 1);
+        let mut all_neighbors = neighbors(mesh);
+        ring1.sort();
+        all_neighbors.sort();
+        assert_eq!(ring1, all_neighbors);
+    }
+
+    #[test]
+    fn test_ring_order_and_huge_k() {
+        let mesh = MeshCode::from_str("533946111").unwrap();
+        for k in 1..=3i32 {
+            // 外周の南の行から北の行へ、各行は西から東の順
+            let mut expected = Vec::new();
+            for dy in -k..=k {
+                for dx in -k..=k {
+                    if dx.abs() == k || dy.abs() == k {
+                        expected.extend(offset_mesh(mesh, dx, dy));
+                    }
+                }
+            }
+            assert_eq!(ring(mesh, k as u32), expected);
+        }
+
+        assert!(ring(mesh, i32::MAX as u32 + 1).is_empty());
+        assert!(ring(mesh, u32::MAX).is_empty());
+        assert!(offset_mesh(mesh, i32::MAX, i32::MIN).is_none());
+    }
+
+    #[test]
+    fn test_ring_walker_steps() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let mut walker = RingWalker::new(mesh);
+
+        let rings: Vec<Vec<MeshCode>> = (0..3).map(|_| walker.step()).collect();
+        assert_eq!(walker.current_ring(), 3);
+        for (i, r) in rings.iter().enumerate() {
+            assert_eq!(r, &ring(mesh, i as u32 + 1));
+            assert_eq!(r.len(), 8 * (i + 1));
+        }
+
+        // リング間で重複しない（中心も含まない）
+        let mut all: Vec<MeshCode> = rings.concat();
+        all.push(mesh);
+        let total = all.len();
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), total);
+
+        walker.reset();
+        assert_eq!(walker.step(), rings[0]);
+    }
+
+    #[test]
+    fn test_find_nearest_where() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let east = neighbor(mesh, Direction::East).unwrap();
+
+        assert_eq!(find_nearest_where(mesh, |m| m == east, 3), Some(east));
+        assert_eq!(find_nearest_where(mesh, |m| m == mesh, 0), Some(mesh));
+        assert_eq!(find_nearest_where(mesh, |m| m == east, 0), None);
+
+        // 複数のメッシュが条件を満たす場合は内側のリングが優先される
+        let far = ring(mesh, 2)[0];
+        assert_eq!(
+            find_nearest_where(mesh, |m| m == far || m == east, 2),
+            Some(east)
+        );
+    }
+
+    #[test]
+    fn test_fourth_half_neighbors_by_quadrant() {
+        // 3次メッシュ53394611の各2分の1メッシュから東西南北へ移動した先
+        // （分割番号: 南西=1、南東=2、北西=3、北東=4）
+        let cases = [
+            (
+                "533946111",
+                "533946113",
+                "533946112",
+                "533946013",
+                "533946102",
+            ),
+            (
+                "533946112",
+                "533946114",
+                "533946121",
+                "533946014",
+                "533946111",
+            ),
+            (
+                "533946113",
+                "533946211",
+                "533946114",
+                "533946111",
+                "533946104",
+            ),
+            (
+                "533946114",
+                "533946212",
+                "533946123",
+                "533946112",
+                "533946113",
+            ),
+        ];
+
+        for (code, north, east, south, west) in cases {
+            let mesh = MeshCode::from_str(code).unwrap();
+            for (direction, expected) in [
+                (Direction::North, north),
+                (Direction::East, east),
+                (Direction::South, south),
+                (Direction::West, west),
+            ] {
+                let got = neighbor(mesh, direction).unwrap();
+                assert_eq!(got.as_string(), expected, "{code} {direction:?}");
+
+                // 中心座標をずらして求めた結果とも一致する
+                let center = mesh_to_center(mesh);
+                let (dx, dy) = direction.offset();
+                let shifted = crate::types::Coordinate::new_unchecked(
+                    center.lat() + dy as f64 * MeshLevel::FourthHalf.lat_size_degrees(),
+                    center.lon() + dx as f64 * MeshLevel::FourthHalf.lon_size_degrees(),
+                );
+                assert_eq!(coord_to_mesh(shifted, MeshLevel::FourthHalf).unwrap(), got);
+            }
+        }
+    }
+
+    #[test]
+    fn test_subdivision_neighbors_match_center_shift() {
+        let coord = crate::types::Coordinate::new(35.6812, 139.7671).unwrap();
+        for level in [
+            MeshLevel::FourthHalf,
+            MeshLevel::FourthQuarter,
+            MeshLevel::FourthEighth,
+        ] {
+            let mesh = coord_to_mesh(coord, level).unwrap();
+            let center = mesh_to_center(mesh);
+            for dy in -3..=3 {
+                for dx in -3..=3 {
+                    let shifted = crate::types::Coordinate::new_unchecked(
+                        center.lat() + dy as f64 * level.lat_size_degrees(),
+                        center.lon() + dx as f64 * level.lon_size_degrees(),
+                    );
+                    assert_eq!(
+                        offset_mesh(mesh, dx, dy),
+                        coord_to_mesh(shifted, level).ok(),
+                        "{mesh} ({dx}, {dy})"
+                    );
+                }
+            }
+        }
+
+        // 北端では範囲外になる
+        let north = coord_to_mesh(
+            crate::types::Coordinate::new(45.999, 141.5).unwrap(),
+            MeshLevel::FourthHalf,
+        )
+        .unwrap();
+        assert_eq!(
+            try_neighbor(north, Direction::North),
+            Err(NeighborError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_grid_around() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let grid = grid_around(mesh, 2, 3);
+        assert_eq!(grid.len(), 5);
+        assert!(grid.iter().all(|row| row.len() == 7));
+        assert_eq!(grid[2][3], Some(mesh));
+
+        let cells: Vec<MeshCode> = grid.iter().flatten().map(|m| m.unwrap()).collect();
+        let unique: alloc::collections::BTreeSet<_> = cells.iter().collect();
+        assert_eq!(unique.len(), cells.len());
+
+        // 北西の角は北へ2つ、西へ3つ移動したメッシュ
+        assert_eq!(
+            grid[0][0],
+            mesh.step(Direction::North, 2)
+                .and_then(|m| m.step(Direction::West, 3))
+        );
+
+        // 日本の西端に接するメッシュでは西側の列が範囲外になる
+        let west_edge = coord_to_mesh(
+            crate::types::Coordinate::new(24.0, 122.001).unwrap(),
+            MeshLevel::Third,
+        )
+        .unwrap();
+        let grid = grid_around(west_edge, 1, 1);
+        assert!(grid.iter().all(|row| row[0].is_none()));
+        assert!(grid.iter().all(|row| row[1].is_some() && row[2].is_some()));
+    }
+
+    #[test]
+    fn test_neighbors_array_indexed_by_direction() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let around = neighbors_array(mesh);
+        assert_eq!(
+            around[Direction::East as usize],
+            neighbor(mesh, Direction::East)
+        );
+        for (i, dir) in Direction::ALL.iter().enumerate() {
+            assert_eq!(around[i], neighbor(mesh, *dir));
+        }
+
+        // 日本の西端では西側の方向がNoneになり、位置は保たれる
+        let west_edge = coord_to_mesh(
+            crate::types::Coordinate::new(24.0, 122.001).unwrap(),
+            MeshLevel::Third,
+        )
+        .unwrap();
+        let around = neighbors_array(west_edge);
+        assert_eq!(around[Direction::West as usize], None);
+        assert!(around[Direction::East as usize].is_some());
+        assert_eq!(around.iter().flatten().count(), neighbors(west_edge).len());
+    }
 }
@@ -0,0 +1,108 @@
+//! メッシュコードの集合演算
+//!
+//! 「今月新たに追加されたメッシュ」のような差分を求めるための補助関数です。
+//! 入力に重複があっても構いません。結果は重複を除いてソートした順で返します。
+//! メッシュコードの等価性はレベルを含めて判定するため、異なるレベルのメッシュは
+//! 同じ桁の並びを持っていても常に別のメッシュとして扱われます。
+//!
+//! `no_std`環境でも使えるよう、内部では`BTreeSet`を使用します。
+
+use crate::types::MeshCode;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+/// `a`に含まれ、`b`に含まれないメッシュコードを返す
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::operations::set::difference;
+/// use jismeshcode::prelude::*;
+///
+/// let this_month = [
+///     MeshCode::from_str("53394611").unwrap(),
+///     MeshCode::from_str("53394612").unwrap(),
+/// ];
+/// let last_month = [MeshCode::from_str("53394611").unwrap()];
+/// assert_eq!(
+///     difference(&this_month, &last_month),
+///     vec![MeshCode::from_str("53394612").unwrap()]
+/// );
+/// ```
+pub fn difference(a: &[MeshCode], b: &[MeshCode]) -> Vec<MeshCode> {
+    let b: BTreeSet<MeshCode> = b.iter().copied().collect();
+    a.iter()
+        .copied()
+        .filter(|mesh| !b.contains(mesh))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// `a`と`b`の両方に含まれるメッシュコードを返す
+pub fn intersection(a: &[MeshCode], b: &[MeshCode]) -> Vec<MeshCode> {
+    let b: BTreeSet<MeshCode> = b.iter().copied().collect();
+    a.iter()
+        .copied()
+        .filter(|mesh| b.contains(mesh))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// `a`と`b`のどちらか一方にだけ含まれるメッシュコードを返す
+pub fn symmetric_difference(a: &[MeshCode], b: &[MeshCode]) -> Vec<MeshCode> {
+    let a: BTreeSet<MeshCode> = a.iter().copied().collect();
+    let b: BTreeSet<MeshCode> = b.iter().copied().collect();
+    a.symmetric_difference(&b).copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MeshLevel;
+
+    fn meshes(codes: &[&str]) -> Vec<MeshCode> {
+        codes
+            .iter()
+            .map(|c| MeshCode::from_str(c).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = meshes(&["53394613", "53394611", "53394612", "53394611"]);
+        let b = meshes(&["53394612", "53394614"]);
+        assert_eq!(difference(&a, &b), meshes(&["53394611", "53394613"]));
+        assert_eq!(difference(&b, &a), meshes(&["53394614"]));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = meshes(&["53394613", "53394611", "53394612"]);
+        let b = meshes(&["53394612", "53394614", "53394611"]);
+        assert_eq!(intersection(&a, &b), meshes(&["53394611", "53394612"]));
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let a = meshes(&["53394613", "53394611", "53394612"]);
+        let b = meshes(&["53394612", "53394614", "53394611"]);
+        assert_eq!(
+            symmetric_difference(&a, &b),
+            meshes(&["53394613", "53394614"])
+        );
+    }
+
+    #[test]
+    fn test_mixed_levels_are_never_equal() {
+        // 同じ桁の並びでも4分の1メッシュと5次メッシュは別のメッシュ
+        let quarter = MeshCode::new(MeshLevel::FourthQuarter, 5339461111).unwrap();
+        let fifth = MeshCode::new(MeshLevel::Fifth, 5339461111).unwrap();
+        let third = MeshCode::from_str("53394611").unwrap();
+
+        assert!(intersection(&[quarter, third], &[fifth]).is_empty());
+        assert_eq!(difference(&[quarter, third], &[fifth]).len(), 2);
+        assert_eq!(symmetric_difference(&[quarter], &[fifth]).len(), 2);
+    }
+}
@@ -0,0 +1,283 @@
+//! メッシュコードの集合に対する集計
+//!
+//! 検索結果のメッシュ群にラベルを配置する位置など集合全体の代表値を求めるほか、
+//! 座標をメッシュ単位にまとめる集計の基本機能を提供します。
+
+use crate::convert::{mesh_to_center, MeshPrefixCache};
+use crate::types::{Coordinate, MeshCode, MeshLevel};
+use crate::utils::distance::haversine_distance;
+use crate::utils::math;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// メッシュの中心座標を重み付きで平均する（重みの合計が0の場合はNone）
+fn weighted_center<F>(meshes: &[MeshCode], weight: F) -> Option<Coordinate>
+where
+    F: Fn(MeshCode) -> f64,
+{
+    let (lat_sum, lon_sum, weight_sum) =
+        meshes
+            .iter()
+            .fold((0.0, 0.0, 0.0), |(lat_sum, lon_sum, weight_sum), &mesh| {
+                let center = mesh_to_center(mesh);
+                let w = weight(mesh);
+                (
+                    lat_sum + center.lat() * w,
+                    lon_sum + center.lon() * w,
+                    weight_sum + w,
+                )
+            });
+
+    if weight_sum <= 0.0 {
+        return None;
+    }
+    Some(Coordinate::new_unchecked(
+        lat_sum / weight_sum,
+        lon_sum / weight_sum,
+    ))
+}
+
+/// メッシュの集合の面積加重重心を求める
+///
+/// 各メッシュの中心座標を[`MeshCode::area_square_meters`]で重み付けして平均します。
+/// レベルの異なるメッシュが混在していても、面積の大きいメッシュほど強く反映されます。
+///
+/// # 引数
+/// * `meshes` - メッシュコードのスライス
+///
+/// # 戻り値
+/// 重心の座標、または入力が空の場合はNone
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::operations::stats::centroid;
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let c = centroid(&neighbors(mesh)).unwrap();
+/// assert!((c.lat() - mesh_to_center(mesh).lat()).abs() < 1e-4);
+/// assert!(centroid(&[]).is_none());
+/// ```
+pub fn centroid(meshes: &[MeshCode]) -> Option<Coordinate> {
+    weighted_center(meshes, |mesh| mesh.area_square_meters())
+}
+
+/// メッシュの集合の中心座標を単純平均する
+///
+/// [`centroid`]と異なり、面積にかかわらず各メッシュを同じ重みで扱います。
+///
+/// # 引数
+/// * `meshes` - メッシュコードのスライス
+///
+/// # 戻り値
+/// 中心座標の平均、または入力が空の場合はNone
+pub fn centroid_unweighted(meshes: &[MeshCode]) -> Option<Coordinate> {
+    weighted_center(meshes, |_| 1.0)
+}
+
+/// 座標の一覧を、それぞれが含まれるメッシュごとにまとめる
+///
+/// 各座標を`level`のメッシュに変換し、メッシュごとに座標の添字（`coords`内の位置）を
+/// 昇順に並べて返します。メッシュ単位で点を数える・集計する処理の基本になります。
+/// 変換に失敗した座標は結果に含まれません。`no_std`環境でも使えるよう、
+/// 結果はメッシュコード順の`BTreeMap`です。
+///
+/// # 引数
+/// * `coords` - 座標のスライス
+/// * `level` - まとめる単位のメッシュレベル
+///
+/// # 戻り値
+/// メッシュコードから座標の添字の一覧への対応
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::operations::stats::group_by_mesh;
+/// use jismeshcode::prelude::*;
+///
+/// let coords = [
+///     Coordinate::new(35.6812, 139.7671).unwrap(),
+///     Coordinate::new(34.7025, 135.4959).unwrap(),
+///     Coordinate::new(35.6813, 139.7672).unwrap(),
+/// ];
+/// let groups = group_by_mesh(&coords, MeshLevel::Third);
+/// let tokyo = MeshCode::from_str("53394611").unwrap();
+/// assert_eq!(groups[&tokyo], vec![0, 2]);
+/// assert_eq!(groups.len(), 2);
+/// ```
+pub fn group_by_mesh(coords: &[Coordinate], level: MeshLevel) -> BTreeMap<MeshCode, Vec<usize>> {
+    let mut cache = MeshPrefixCache::new();
+    let mut groups: BTreeMap<MeshCode, Vec<usize>> = BTreeMap::new();
+    for (i, &coord) in coords.iter().enumerate() {
+        if let Ok(mesh) = cache.convert(coord, level) {
+            groups.entry(mesh).or_default().push(i);
+        }
+    }
+    groups
+}
+
+/// 対象座標から各メッシュへの逆距離加重（IDW）の重みを求める
+///
+/// 対象座標と各メッシュの中心とのHaversine距離`d`から`1 / d^power`を求め、
+/// 合計が1.0になるよう正規化した重みを`meshes`と同じ順序で返します。
+/// メッシュに割り当てた値を対象座標へ補間する場合に、各値にこの重みを掛けて
+/// 合計します。対象座標がいずれかのメッシュの中心と一致する場合は、
+/// 一致したメッシュだけに均等に重みを割り当てます。`meshes`が空の場合は空になります。
+///
+/// # 引数
+/// * `target` - 補間する座標
+/// * `meshes` - 値を持つメッシュコードのスライス
+/// * `power` - 距離の指数（一般的には2.0）
+///
+/// # 戻り値
+/// 各メッシュの重みのベクター（合計1.0）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::operations::stats::idw_weights;
+/// use jismeshcode::prelude::*;
+///
+/// let a = MeshCode::from_str("53394611").unwrap();
+/// let b = MeshCode::from_str("53394613").unwrap();
+/// let target = mesh_to_center(MeshCode::from_str("53394612").unwrap());
+///
+/// let weights = idw_weights(target, &[a, b], 2.0);
+/// assert!((weights[0] - 0.5).abs() < 1e-6);
+/// assert!((weights[1] - 0.5).abs() < 1e-6);
+/// ```
+pub fn idw_weights(target: Coordinate, meshes: &[MeshCode], power: f64) -> Vec<f64> {
+    let distances: Vec<f64> = meshes
+        .iter()
+        .map(|&mesh| haversine_distance(target, mesh_to_center(mesh)))
+        .collect();
+
+    let exact = distances.iter().filter(|&&d| d == 0.0).count();
+    let raw: Vec<f64> = if exact > 0 {
+        distances
+            .iter()
+            .map(|&d| if d == 0.0 { 1.0 } else { 0.0 })
+            .collect()
+    } else {
+        distances
+            .iter()
+            .map(|&d| 1.0 / math::powf(d, power))
+            .collect()
+    };
+
+    let total = raw.iter().fold(0.0, |acc, w| acc + w);
+    raw.into_iter().map(|w| w / total).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{children, neighbors};
+
+    #[test]
+    fn test_centroid_of_symmetric_block() {
+        let center = MeshCode::from_str("53394655").unwrap();
+        let mut block = neighbors(center);
+        block.push(center);
+        assert_eq!(block.len(), 9);
+
+        let expected = mesh_to_center(center);
+        let unweighted = centroid_unweighted(&block).unwrap();
+        assert!(
+            (unweighted.lat() - expected.lat()).abs() < 1e-9,
+            "{unweighted:?} {expected:?}"
+        );
+        assert!(
+            (unweighted.lon() - expected.lon()).abs() < 1e-9,
+            "{unweighted:?} {expected:?}"
+        );
+
+        // 南の行ほど面積がわずかに大きいため、面積加重では緯度がわずかに南へずれる
+        let weighted = centroid(&block).unwrap();
+        assert!(
+            (weighted.lat() - expected.lat()).abs() < 1e-4,
+            "{weighted:?}"
+        );
+        assert!(weighted.lat() <= expected.lat());
+        assert!(
+            (weighted.lon() - expected.lon()).abs() < 1e-9,
+            "{weighted:?}"
+        );
+
+        assert!(centroid(&[]).is_none());
+        assert!(centroid_unweighted(&[]).is_none());
+    }
+
+    #[test]
+    fn test_centroid_weights_by_area() {
+        // 2次メッシュ1つと、その南西の角にある3次メッシュ1つ
+        let second = MeshCode::from_str("533946").unwrap();
+        let small = children(second)[0];
+        let meshes = [second, small];
+
+        let weighted = centroid(&meshes).unwrap();
+        let unweighted = centroid_unweighted(&meshes).unwrap();
+        let second_center = mesh_to_center(second);
+
+        // 面積加重では大きなメッシュの中心に近くなる
+        let dist = |c: Coordinate| {
+            (c.lat() - second_center.lat()).abs() + (c.lon() - second_center.lon()).abs()
+        };
+        assert!(dist(weighted) < dist(unweighted));
+        assert!(dist(weighted) < 0.01);
+    }
+
+    #[test]
+    fn test_group_by_mesh() {
+        let coords = [
+            Coordinate::new(35.6812, 139.7671).unwrap(),
+            Coordinate::new(35.6815, 139.7675).unwrap(),
+            Coordinate::new(43.0621, 141.3544).unwrap(),
+            Coordinate::new_unchecked(80.0, 139.0),
+            Coordinate::new(35.6811, 139.7670).unwrap(),
+        ];
+        let groups = group_by_mesh(&coords, MeshLevel::Third);
+
+        let tokyo = crate::convert::coord_to_mesh(coords[0], MeshLevel::Third).unwrap();
+        assert_eq!(groups[&tokyo], alloc::vec![0, 1, 4]);
+        assert_eq!(groups.len(), 2);
+
+        // 変換できない座標は除かれる
+        let total: usize = groups.values().map(Vec::len).sum();
+        assert_eq!(total, 4);
+
+        assert!(group_by_mesh(&[], MeshLevel::Third).is_empty());
+    }
+
+    #[test]
+    fn test_idw_weights() {
+        let center = MeshCode::from_str("53394611").unwrap();
+        let meshes = neighbors(center);
+        let target = Coordinate::new(35.6812, 139.7671).unwrap();
+
+        let weights = idw_weights(target, &meshes, 2.0);
+        assert_eq!(weights.len(), meshes.len());
+        let total = weights.iter().fold(0.0, |acc, w| acc + w);
+        assert!((total - 1.0).abs() < 1e-12);
+
+        let closest = (0..meshes.len())
+            .min_by(|&i, &j| {
+                let di = haversine_distance(target, mesh_to_center(meshes[i]));
+                let dj = haversine_distance(target, mesh_to_center(meshes[j]));
+                di.total_cmp(&dj)
+            })
+            .unwrap();
+        let heaviest = (0..weights.len())
+            .max_by(|&i, &j| weights[i].total_cmp(&weights[j]))
+            .unwrap();
+        assert_eq!(closest, heaviest);
+
+        // メッシュの中心と一致する場合はそのメッシュだけに重みがかかる
+        let on_center = idw_weights(mesh_to_center(meshes[3]), &meshes, 2.0);
+        assert_eq!(on_center[3], 1.0);
+        assert_eq!(on_center.iter().filter(|&&w| w == 0.0).count(), 7);
+
+        assert!(idw_weights(target, &[], 2.0).is_empty());
+    }
+}
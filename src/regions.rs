@@ -0,0 +1,166 @@
+//! メッシュコードから都道府県を引くための簡易的な対応表
+//!
+//! 各都道府県を、本土と主な離島ごとのおおよその緯度・経度範囲（矩形）で近似しています。
+//! メッシュの範囲と矩形が重なる都道府県を返すため、結果は実際に交差する都道府県を
+//! 含みますが、海上や隣接県など実際には交差しない都道府県も含まれ得ます。
+//! 候補の絞り込みなど、概略の判定にのみ使用してください。
+
+use crate::convert::mesh_to_bounds;
+use crate::types::MeshCode;
+use alloc::vec::Vec;
+
+/// 都道府県名と、その範囲を近似する矩形（南端の緯度、北端の緯度、西端の経度、東端の経度）
+///
+/// 全国地方公共団体コードの順に並べ、離島など本土から離れた地域は同じ都道府県名で
+/// 別の矩形として持ちます。
+const PREFECTURE_BOXES: &[(&str, [f64; 4])] = &[
+    ("北海道", [41.35, 45.56, 139.33, 148.90]),
+    ("青森県", [40.22, 41.56, 139.49, 141.69]),
+    ("岩手県", [38.74, 40.45, 140.65, 142.08]),
+    ("宮城県", [37.77, 39.00, 140.27, 141.68]),
+    ("秋田県", [38.87, 40.51, 139.69, 140.99]),
+    ("山形県", [37.73, 39.21, 139.52, 140.65]),
+    ("福島県", [36.79, 37.98, 139.16, 141.05]),
+    ("茨城県", [35.74, 36.95, 139.69, 140.85]),
+    ("栃木県", [36.20, 37.16, 139.33, 140.29]),
+    ("群馬県", [35.98, 37.06, 138.40, 139.67]),
+    ("埼玉県", [35.75, 36.28, 138.71, 139.90]),
+    ("千葉県", [34.90, 36.10, 139.74, 140.87]),
+    ("東京都", [35.50, 35.90, 138.94, 139.92]),
+    // 伊豆諸島
+    ("東京都", [32.40, 34.80, 139.10, 140.00]),
+    // 小笠原諸島
+    ("東京都", [24.00, 27.80, 140.80, 142.30]),
+    // 沖ノ鳥島
+    ("東京都", [20.40, 20.50, 136.00, 136.10]),
+    // 南鳥島
+    ("東京都", [24.20, 24.35, 153.90, 154.00]),
+    ("神奈川県", [35.13, 35.67, 138.91, 139.79]),
+    ("新潟県", [36.73, 38.55, 137.63, 139.90]),
+    ("富山県", [36.27, 36.98, 136.76, 137.76]),
+    ("石川県", [36.07, 37.86, 136.24, 137.37]),
+    ("福井県", [35.34, 36.30, 135.44, 136.83]),
+    ("山梨県", [35.17, 35.97, 138.18, 139.13]),
+    ("長野県", [35.20, 37.03, 137.32, 138.74]),
+    ("岐阜県", [35.13, 36.47, 136.28, 137.65]),
+    ("静岡県", [34.57, 35.65, 137.47, 139.18]),
+    ("愛知県", [34.57, 35.43, 136.67, 137.84]),
+    ("三重県", [33.72, 35.26, 135.85, 136.99]),
+    ("滋賀県", [34.79, 35.70, 135.76, 136.46]),
+    ("京都府", [34.71, 35.78, 134.85, 136.06]),
+    ("大阪府", [34.27, 35.05, 135.09, 135.75]),
+    ("兵庫県", [34.16, 35.67, 134.25, 135.47]),
+    ("奈良県", [33.86, 34.78, 135.54, 136.23]),
+    ("和歌山県", [33.43, 34.39, 135.00, 136.02]),
+    ("鳥取県", [35.06, 35.62, 133.14, 134.51]),
+    ("島根県", [34.30, 36.34, 131.67, 133.39]),
+    ("岡山県", [34.30, 35.35, 133.27, 134.41]),
+    ("広島県", [34.03, 35.11, 132.04, 133.47]),
+    ("山口県", [33.71, 34.80, 130.78, 132.49]),
+    ("徳島県", [33.54, 34.25, 133.66, 134.82]),
+    ("香川県", [34.02, 34.57, 133.45, 134.45]),
+    ("愛媛県", [32.88, 34.31, 132.01, 133.69]),
+    ("高知県", [32.70, 33.88, 132.48, 134.31]),
+    ("福岡県", [33.00, 34.25, 129.99, 131.19]),
+    ("佐賀県", [32.95, 33.62, 129.74, 130.54]),
+    ("長崎県", [32.57, 34.73, 128.10, 130.38]),
+    ("熊本県", [32.09, 33.19, 129.95, 131.33]),
+    ("大分県", [32.72, 33.74, 130.82, 132.09]),
+    ("宮崎県", [31.36, 32.84, 130.71, 131.89]),
+    ("鹿児島県", [30.00, 32.30, 129.40, 131.40]),
+    // トカラ列島・奄美群島
+    ("鹿児島県", [27.00, 30.00, 128.40, 130.30]),
+    ("沖縄県", [25.90, 27.90, 126.60, 128.40]),
+    // 大東諸島
+    ("沖縄県", [24.40, 26.00, 131.10, 131.40]),
+    // 先島諸島・尖閣諸島
+    ("沖縄県", [24.00, 26.00, 122.90, 125.50]),
+];
+
+/// メッシュと交差する可能性のある都道府県名を返す
+///
+/// メッシュの範囲と、各都道府県を近似した矩形とが重なる（辺で接するだけの場合を除く）
+/// 都道府県を、全国地方公共団体コードの順に返します。近似のため、実際には
+/// 交差しない都道府県が含まれることがあります。どのレベルのメッシュにも使用できます。
+///
+/// # 引数
+/// * `mesh` - メッシュコード
+///
+/// # 戻り値
+/// 都道府県名のベクター（重複なし）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+/// use jismeshcode::regions::prefectures_for_mesh;
+///
+/// let mesh = MeshCode::from_str("5339").unwrap();
+/// let prefectures = prefectures_for_mesh(mesh);
+/// assert!(prefectures.contains(&"東京都"));
+/// assert!(!prefectures.contains(&"北海道"));
+/// ```
+pub fn prefectures_for_mesh(mesh: MeshCode) -> Vec<&'static str> {
+    let bounds = mesh_to_bounds(mesh);
+
+    let mut result: Vec<&'static str> = Vec::new();
+    for &(name, [min_lat, max_lat, min_lon, max_lon]) in PREFECTURE_BOXES {
+        let overlaps = bounds.min_lat() < max_lat
+            && bounds.max_lat() > min_lat
+            && bounds.min_lon() < max_lon
+            && bounds.max_lon() > min_lon;
+        if overlaps && result.last() != Some(&name) {
+            result.push(name);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prefectures(code: &str) -> Vec<&'static str> {
+        prefectures_for_mesh(MeshCode::from_str(code).unwrap())
+    }
+
+    #[test]
+    fn test_well_known_first_level_meshes() {
+        // 東京周辺
+        let tokyo = prefectures("5339");
+        for name in ["東京都", "神奈川県", "埼玉県", "千葉県"] {
+            assert!(tokyo.contains(&name), "{name} not in {tokyo:?}");
+        }
+        assert!(!tokyo.contains(&"大阪府"));
+
+        // 札幌周辺
+        assert_eq!(prefectures("6441"), alloc::vec!["北海道"]);
+
+        // 那覇周辺
+        assert_eq!(prefectures("3927"), alloc::vec!["沖縄県"]);
+
+        // 京阪神
+        let kansai = prefectures("5235");
+        for name in ["京都府", "大阪府", "兵庫県"] {
+            assert!(kansai.contains(&name), "{name} not in {kansai:?}");
+        }
+        assert!(!kansai.contains(&"東京都"));
+    }
+
+    #[test]
+    fn test_finer_meshes_and_islands() {
+        // 東京駅を含む3次メッシュ
+        let station = prefectures("53394611");
+        assert!(station.contains(&"東京都"));
+        assert!(!station.contains(&"埼玉県"));
+
+        // 父島を含む1次メッシュ（北緯26度40分〜27度20分、東経142〜143度）
+        assert_eq!(prefectures("4042"), alloc::vec!["東京都"]);
+
+        // 重複なく、表の順序で並ぶ
+        let names = prefectures("5339");
+        let mut deduped = names.clone();
+        deduped.dedup();
+        assert_eq!(names, deduped);
+    }
+}
@@ -0,0 +1,261 @@
+use crate::error::{MeshCodeError, Result};
+use crate::types::{BoundingBox, MeshCode, MeshLevel};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// 境界ボックス内のメッシュの集合を1メッシュ1ビットで保持するビットセット
+///
+/// 固定の範囲について「このメッシュは対象地域に含まれるか」を高速に判定するための
+/// 集合です。境界ボックスと接する指定レベルのメッシュ1つにつき1ビットを割り当てるため、
+/// `BTreeSet<MeshCode>`などと比べて省メモリで、判定も定数時間で行えます。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let bbox = BoundingBox::new(
+///     Coordinate::new(35.6, 139.7).unwrap(),
+///     Coordinate::new(35.7, 139.8).unwrap(),
+/// );
+/// let mut set = MeshBitSet::new(bbox, MeshLevel::Third);
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// assert_eq!(set.insert(mesh), Ok(true));
+/// assert!(set.contains(mesh));
+/// assert_eq!(set.len(), 1);
+///
+/// // 範囲外のメッシュは追加できない
+/// let osaka = MeshCode::from_str("52350349").unwrap();
+/// assert!(set.insert(osaka).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeshBitSet {
+    level: MeshLevel,
+    lat_range: Range<i64>,
+    lon_range: Range<i64>,
+    bits: Vec<u64>,
+}
+
+impl MeshBitSet {
+    /// 境界ボックスと接する指定レベルのメッシュを対象とする空の集合を作成する
+    ///
+    /// # 引数
+    /// * `bbox` - 対象とする範囲
+    /// * `level` - 対象とするメッシュレベル
+    pub fn new(bbox: BoundingBox, level: MeshLevel) -> Self {
+        let (lat_range, lon_range) = bbox.grid_index_bounds(level);
+        Self::with_ranges(level, lat_range, lon_range)
+    }
+
+    fn with_ranges(level: MeshLevel, lat_range: Range<i64>, lon_range: Range<i64>) -> Self {
+        let cells = (range_len(&lat_range) * range_len(&lon_range)) as usize;
+        MeshBitSet {
+            level,
+            lat_range,
+            lon_range,
+            bits: vec![0; cells.div_ceil(64)],
+        }
+    }
+
+    /// 対象とするメッシュレベルを返す
+    pub fn level(&self) -> MeshLevel {
+        self.level
+    }
+
+    /// メッシュを追加する
+    ///
+    /// # 戻り値
+    /// 新たに追加された場合はtrue、すでに含まれていた場合はfalse。
+    /// レベルが異なる場合や対象範囲外のメッシュの場合は[`MeshCodeError::OutOfRange`]
+    pub fn insert(&mut self, mesh: MeshCode) -> Result<bool> {
+        let index = self.bit_index(mesh).ok_or(MeshCodeError::OutOfRange)?;
+        let (word, mask) = (index / 64, 1u64 << (index % 64));
+        let inserted = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+        Ok(inserted)
+    }
+
+    /// メッシュが集合に含まれるか判定する（対象範囲外のメッシュはfalse）
+    pub fn contains(&self, mesh: MeshCode) -> bool {
+        self.bit_index(mesh)
+            .is_some_and(|index| self.bits[index / 64] & (1u64 << (index % 64)) != 0)
+    }
+
+    /// 集合に含まれるメッシュの数を返す
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// 集合が空かどうかを返す
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// 集合に含まれるメッシュを、南の行から北の行へ、各行は西から東の順に列挙する
+    pub fn iter(&self) -> impl Iterator<Item = MeshCode> + '_ {
+        let cols = range_len(&self.lon_range);
+        self.bits
+            .iter()
+            .enumerate()
+            .flat_map(|(word_index, &word)| {
+                (0..64)
+                    .filter(move |bit| word & (1u64 << bit) != 0)
+                    .map(move |bit| (word_index * 64 + bit) as i64)
+            })
+            .filter_map(move |index| {
+                let lat = self.lat_range.start + index / cols;
+                let lon = self.lon_range.start + index % cols;
+                MeshCode::from_grid_index(self.level, lat, lon)
+            })
+    }
+
+    /// 2つの集合の和集合を返す
+    ///
+    /// 結果の対象範囲は両方の対象範囲を含む最小の範囲になります。
+    /// レベルが異なる場合はNoneを返します。
+    pub fn union(&self, other: &MeshBitSet) -> Option<MeshBitSet> {
+        if self.level != other.level {
+            return None;
+        }
+
+        let lat_range = self.lat_range.start.min(other.lat_range.start)
+            ..self.lat_range.end.max(other.lat_range.end);
+        let lon_range = self.lon_range.start.min(other.lon_range.start)
+            ..self.lon_range.end.max(other.lon_range.end);
+        let mut result = Self::with_ranges(self.level, lat_range, lon_range);
+        for mesh in self.iter().chain(other.iter()) {
+            result.insert(mesh).ok()?;
+        }
+        Some(result)
+    }
+
+    /// 2つの集合の積集合を返す
+    ///
+    /// 結果の対象範囲は両方の対象範囲の重なりになります。
+    /// レベルが異なる場合はNoneを返します。
+    pub fn intersection(&self, other: &MeshBitSet) -> Option<MeshBitSet> {
+        if self.level != other.level {
+            return None;
+        }
+
+        let lat_start = self.lat_range.start.max(other.lat_range.start);
+        let lon_start = self.lon_range.start.max(other.lon_range.start);
+        let lat_range = lat_start..self.lat_range.end.min(other.lat_range.end).max(lat_start);
+        let lon_range = lon_start..self.lon_range.end.min(other.lon_range.end).max(lon_start);
+        let mut result = Self::with_ranges(self.level, lat_range, lon_range);
+        for mesh in self.iter().filter(|&mesh| other.contains(mesh)) {
+            result.insert(mesh).ok()?;
+        }
+        Some(result)
+    }
+
+    /// メッシュに対応するビットの位置を返す（対象外の場合はNone）
+    fn bit_index(&self, mesh: MeshCode) -> Option<usize> {
+        if mesh.level() != self.level {
+            return None;
+        }
+
+        let (lat, lon) = mesh.grid_index();
+        if !self.lat_range.contains(&lat) || !self.lon_range.contains(&lon) {
+            return None;
+        }
+        let row = lat - self.lat_range.start;
+        let col = lon - self.lon_range.start;
+        Some((row * range_len(&self.lon_range) + col) as usize)
+    }
+}
+
+fn range_len(range: &Range<i64>) -> i64 {
+    (range.end - range.start).max(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spatial::mesh_codes_in_bbox;
+    use crate::types::Coordinate;
+    use alloc::collections::BTreeSet;
+
+    fn bbox(min_lat: f64, min_lon: f64, max_lat: f64, max_lon: f64) -> BoundingBox {
+        BoundingBox::new(
+            Coordinate::new(min_lat, min_lon).unwrap(),
+            Coordinate::new(max_lat, max_lon).unwrap(),
+        )
+    }
+
+    fn filled(bbox: BoundingBox, level: MeshLevel) -> MeshBitSet {
+        let mut set = MeshBitSet::new(bbox, level);
+        for mesh in mesh_codes_in_bbox(bbox, level) {
+            set.insert(mesh).unwrap();
+        }
+        set
+    }
+
+    #[test]
+    fn test_insert_and_contains() {
+        let area = bbox(35.6, 139.7, 35.7, 139.8);
+        let mut set = MeshBitSet::new(area, MeshLevel::Third);
+        assert!(set.is_empty());
+
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let other = MeshCode::from_str("53394612").unwrap();
+        assert_eq!(set.insert(mesh), Ok(true));
+        assert_eq!(set.insert(mesh), Ok(false));
+        assert!(set.contains(mesh));
+        assert!(!set.contains(other));
+        assert_eq!(set.len(), 1);
+
+        // 範囲外・異なるレベルのメッシュは拒否される
+        let outside = MeshCode::from_str("52350349").unwrap();
+        let coarse = MeshCode::from_str("533946").unwrap();
+        assert_eq!(set.insert(outside), Err(MeshCodeError::OutOfRange));
+        assert_eq!(set.insert(coarse), Err(MeshCodeError::OutOfRange));
+        assert!(!set.contains(outside));
+    }
+
+    #[test]
+    fn test_iter_returns_inserted_meshes() {
+        let area = bbox(35.6, 139.7, 35.7, 139.8);
+        let set = filled(area, MeshLevel::Third);
+        let expected: BTreeSet<MeshCode> = mesh_codes_in_bbox(area, MeshLevel::Third).collect();
+        let actual: BTreeSet<MeshCode> = set.iter().collect();
+        assert_eq!(actual, expected);
+        assert_eq!(set.len(), expected.len());
+    }
+
+    #[test]
+    fn test_union_and_intersection_of_overlapping_regions() {
+        let a_box = bbox(35.6, 139.7, 35.7, 139.8);
+        let b_box = bbox(35.65, 139.75, 35.75, 139.85);
+        let a = filled(a_box, MeshLevel::Third);
+        let b = filled(b_box, MeshLevel::Third);
+
+        let a_set: BTreeSet<MeshCode> = a.iter().collect();
+        let b_set: BTreeSet<MeshCode> = b.iter().collect();
+
+        let union = a.union(&b).unwrap();
+        let expected: BTreeSet<MeshCode> = a_set.union(&b_set).copied().collect();
+        assert_eq!(union.iter().collect::<BTreeSet<_>>(), expected);
+        assert_eq!(union.len(), expected.len());
+
+        let intersection = a.intersection(&b).unwrap();
+        let expected: BTreeSet<MeshCode> = a_set.intersection(&b_set).copied().collect();
+        assert!(!expected.is_empty());
+        assert_eq!(intersection.iter().collect::<BTreeSet<_>>(), expected);
+
+        let other_level = MeshBitSet::new(a_box, MeshLevel::Second);
+        assert!(a.union(&other_level).is_none());
+    }
+
+    #[test]
+    fn test_intersection_of_disjoint_regions_is_empty() {
+        let a = filled(bbox(35.6, 139.7, 35.65, 139.75), MeshLevel::Third);
+        let b = filled(bbox(34.6, 135.4, 34.7, 135.5), MeshLevel::Third);
+        assert!(a.intersection(&b).unwrap().is_empty());
+    }
+}
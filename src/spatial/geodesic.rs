@@ -0,0 +1,142 @@
+use crate::convert::coord_to_mesh;
+use crate::types::{Coordinate, MeshCode, MeshLevel};
+use crate::utils::distance::haversine_distance;
+use crate::utils::math;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+/// 2点を結ぶ大円（測地線）上のメッシュコードを取得する
+///
+/// 始点から終点までの大円経路を`samples`個の点で等間隔にサンプリングし、
+/// 各点を含むメッシュを経路の順に重複なく返します。
+///
+/// サンプル数が少ないと、点と点の間で経路がかすめるだけのメッシュを
+/// 取りこぼすことがあります。`samples`に`None`を指定すると、2点間の距離と
+/// メッシュのおおよそのサイズから、メッシュサイズの4分の1以下の間隔になる
+/// サンプル数を自動で決定します。
+///
+/// # 引数
+/// * `start` - 始点の座標
+/// * `end` - 終点の座標
+/// * `level` - 目的のメッシュレベル
+/// * `samples` - サンプリングする点の数（始点と終点を含む）、または自動決定する場合はNone
+///
+/// # 戻り値
+/// 経路上のメッシュコードのベクター（始点側から順に並ぶ）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let shinjuku = Coordinate::new(35.6896, 139.7006).unwrap();
+/// let meshes = mesh_codes_along_geodesic(tokyo, shinjuku, MeshLevel::Third, None);
+/// assert_eq!(meshes.first(), coord_to_mesh(tokyo, MeshLevel::Third).ok().as_ref());
+/// assert_eq!(meshes.last(), coord_to_mesh(shinjuku, MeshLevel::Third).ok().as_ref());
+/// ```
+pub fn mesh_codes_along_geodesic(
+    start: Coordinate,
+    end: Coordinate,
+    level: MeshLevel,
+    samples: Option<usize>,
+) -> Vec<MeshCode> {
+    let distance = haversine_distance(start, end);
+    let samples = samples.unwrap_or_else(|| default_samples(distance, level));
+
+    let mut seen = BTreeSet::new();
+    let mut result = Vec::new();
+    for i in 0..samples {
+        let fraction = if samples == 1 {
+            0.0
+        } else {
+            i as f64 / (samples - 1) as f64
+        };
+        let Some(point) = interpolate_great_circle(start, end, fraction) else {
+            continue;
+        };
+        if let Ok(mesh) = coord_to_mesh(point, level) {
+            if seen.insert(mesh) {
+                result.push(mesh);
+            }
+        }
+    }
+    result
+}
+
+/// メッシュサイズの4分の1以下の間隔になるサンプル数を求める
+fn default_samples(distance: f64, level: MeshLevel) -> usize {
+    let interval = level.approximate_size_meters() / 4.0;
+    math::floor(distance / interval) as usize + 2
+}
+
+/// 大円上で始点から`fraction`（0.0〜1.0）の位置にある座標を求める
+///
+/// 求めた座標が日本の範囲外の場合はNoneを返します。
+fn interpolate_great_circle(
+    start: Coordinate,
+    end: Coordinate,
+    fraction: f64,
+) -> Option<Coordinate> {
+    let point = start.interpolate(end, fraction);
+    Coordinate::new(point.lat(), point.lon()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_arc_is_contiguous() {
+        let start = Coordinate::new(35.6812, 139.7671).unwrap();
+        let end = Coordinate::new(35.6200, 139.6500).unwrap();
+        let meshes = mesh_codes_along_geodesic(start, end, MeshLevel::Third, None);
+
+        assert!(meshes.len() > 2);
+        assert_eq!(meshes[0], coord_to_mesh(start, MeshLevel::Third).unwrap());
+        assert_eq!(
+            *meshes.last().unwrap(),
+            coord_to_mesh(end, MeshLevel::Third).unwrap()
+        );
+        for pair in meshes.windows(2) {
+            assert!(
+                pair[0].is_neighbor_of(&pair[1]).is_some(),
+                "{} -> {}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn test_interpolation_endpoints() {
+        let start = Coordinate::new(35.0, 135.0).unwrap();
+        let end = Coordinate::new(43.0, 141.0).unwrap();
+        let first = interpolate_great_circle(start, end, 0.0).unwrap();
+        let last = interpolate_great_circle(start, end, 1.0).unwrap();
+        assert!(haversine_distance(first, start) < 1e-3);
+        assert!(haversine_distance(last, end) < 1e-3);
+
+        let mid = interpolate_great_circle(start, end, 0.5).unwrap();
+        let to_start = haversine_distance(mid, start);
+        let to_end = haversine_distance(mid, end);
+        assert!((to_start - to_end).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_same_point_and_sample_counts() {
+        let point = Coordinate::new(35.6812, 139.7671).unwrap();
+        let mesh = coord_to_mesh(point, MeshLevel::Third).unwrap();
+        assert_eq!(
+            mesh_codes_along_geodesic(point, point, MeshLevel::Third, None),
+            vec![mesh]
+        );
+
+        let end = Coordinate::new(35.6200, 139.6500).unwrap();
+        assert!(mesh_codes_along_geodesic(point, end, MeshLevel::Third, Some(0)).is_empty());
+        assert_eq!(
+            mesh_codes_along_geodesic(point, end, MeshLevel::Third, Some(1)),
+            vec![mesh]
+        );
+    }
+}
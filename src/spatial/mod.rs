@@ -1,5 +1,22 @@
+mod bitset;
+mod geodesic;
+mod polygon;
 mod radius;
 mod range;
 
-pub use radius::{mesh_codes_in_radius, mesh_codes_in_radius_from_mesh, MeshCodeRadiusIterator};
-pub use range::{mesh_codes_in_bbox, MeshCodeIterator};
+pub use bitset::MeshBitSet;
+pub use geodesic::mesh_codes_along_geodesic;
+pub use polygon::{
+    mesh_codes_covering_polygon, mesh_codes_fully_inside_polygon, mesh_codes_in_polygon,
+};
+#[cfg(feature = "rayon")]
+pub use radius::mesh_codes_in_radius_par;
+pub use radius::{
+    mesh_circle_overlap_fraction, mesh_codes_in_radius, mesh_codes_in_radius_from_mesh,
+    mesh_codes_in_radius_from_mesh_overlap, radius_coverage_ratio, MeshCodeRadiusIterator,
+};
+pub use range::{
+    bbox_grid_dimensions, bbox_mesh_grid, boundary_meshes_of_bbox, mesh_codes_in_bbox,
+    mesh_codes_in_bbox_grid, mesh_codes_in_bbox_with_centers, mesh_codes_in_bboxes,
+    smallest_containing_mesh, MeshCodeIterator,
+};
@@ -0,0 +1,477 @@
+use crate::convert::coord_to_mesh;
+use crate::types::{BoundingBox, Coordinate, MeshCode, MeshLevel};
+use alloc::vec::Vec;
+
+/// 多角形内のメッシュコードを取得する
+///
+/// 多角形の境界ボックス内の候補メッシュを列挙し、メッシュの中心が多角形の内側にある
+/// ものだけを返します（中心判定）。多角形の辺にかかるメッシュは、中心が内側に
+/// あれば含まれ、外側にあれば含まれません。内外判定にはレイキャスティング法を
+/// 使用し、頂点の順序（時計回り・反時計回り）は問いません。
+///
+/// 頂点が3個未満の場合は空のベクターを返します。
+///
+/// # 引数
+/// * `vertices` - 多角形の頂点（最後の頂点と最初の頂点は自動的に結ばれます）
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// 中心が多角形内にあるメッシュコードのベクター（南から北、西から東の順）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let triangle = [
+///     Coordinate::new(35.60, 139.70).unwrap(),
+///     Coordinate::new(35.60, 139.80).unwrap(),
+///     Coordinate::new(35.70, 139.75).unwrap(),
+/// ];
+/// let meshes = mesh_codes_in_polygon(&triangle, MeshLevel::Third);
+/// assert!(!meshes.is_empty());
+/// ```
+pub fn mesh_codes_in_polygon(vertices: &[Coordinate], level: MeshLevel) -> Vec<MeshCode> {
+    let Some(bbox) = polygon_bbox(vertices) else {
+        return Vec::new();
+    };
+
+    bbox.tile_by_level(level)
+        .into_iter()
+        .map(|tile| tile.center())
+        .filter(|&center| point_in_polygon(center, vertices))
+        .filter_map(|center| coord_to_mesh(center, level).ok())
+        .collect()
+}
+
+/// 多角形と重なるすべてのメッシュコードを取得する
+///
+/// [`mesh_codes_in_polygon`]の中心判定とは異なり、多角形と少しでも重なるメッシュを
+/// すべて返します（被覆判定）。次のいずれかを満たすメッシュが含まれます。
+///
+/// - メッシュの角のいずれかが多角形の内側にある
+/// - 多角形の頂点のいずれかがメッシュ内にある
+/// - 多角形の辺がメッシュの辺と交差する
+///
+/// 結果は常に[`mesh_codes_in_polygon`]の結果を包含します。
+/// 頂点が3個未満の場合は空のベクターを返します。
+///
+/// # 引数
+/// * `vertices` - 多角形の頂点（最後の頂点と最初の頂点は自動的に結ばれます）
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// 多角形と重なるメッシュコードのベクター（南から北、西から東の順）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let triangle = [
+///     Coordinate::new(35.60, 139.70).unwrap(),
+///     Coordinate::new(35.60, 139.80).unwrap(),
+///     Coordinate::new(35.70, 139.75).unwrap(),
+/// ];
+/// let covering = mesh_codes_covering_polygon(&triangle, MeshLevel::Third);
+/// let centered = mesh_codes_in_polygon(&triangle, MeshLevel::Third);
+/// assert!(covering.len() >= centered.len());
+/// ```
+pub fn mesh_codes_covering_polygon(vertices: &[Coordinate], level: MeshLevel) -> Vec<MeshCode> {
+    let Some(bbox) = polygon_bbox(vertices) else {
+        return Vec::new();
+    };
+
+    // 辺や頂点で接するだけのメッシュも含めるため、候補を1メッシュ分外側まで広げる
+    let (lat_size, lon_size) = (level.lat_size_degrees(), level.lon_size_degrees());
+    let candidates = BoundingBox::new(
+        Coordinate::new_unchecked(bbox.min_lat() - lat_size, bbox.min_lon() - lon_size),
+        Coordinate::new_unchecked(bbox.max_lat() + lat_size, bbox.max_lon() + lon_size),
+    );
+
+    candidates
+        .tile_by_level(level)
+        .into_iter()
+        .filter(|tile| rect_intersects_polygon(tile, vertices))
+        .filter_map(|tile| coord_to_mesh(tile.center(), level).ok())
+        .collect()
+}
+
+/// 多角形の内側に完全に含まれるメッシュコードを取得する
+///
+/// メッシュの4隅がすべて多角形の内側にあり、多角形の頂点がメッシュの内部に
+/// 入り込んでおらず、多角形の辺がメッシュの辺を横切らないメッシュだけを返します（内部判定）。辺にかかるメッシュを
+/// 含まないため、メッシュ単位の集計を多角形内に厳密に限定したい場合に使用します。
+///
+/// 結果は常に[`mesh_codes_in_polygon`]の結果に含まれます。
+/// 頂点が3個未満の場合は空のベクターを返します。
+///
+/// # 引数
+/// * `vertices` - 多角形の頂点（最後の頂点と最初の頂点は自動的に結ばれます）
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// 多角形に完全に含まれるメッシュコードのベクター（南から北、西から東の順）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let triangle = [
+///     Coordinate::new(35.60, 139.70).unwrap(),
+///     Coordinate::new(35.60, 139.80).unwrap(),
+///     Coordinate::new(35.70, 139.75).unwrap(),
+/// ];
+/// let inside = mesh_codes_fully_inside_polygon(&triangle, MeshLevel::Third);
+/// let centered = mesh_codes_in_polygon(&triangle, MeshLevel::Third);
+/// assert!(inside.len() <= centered.len());
+/// ```
+pub fn mesh_codes_fully_inside_polygon(vertices: &[Coordinate], level: MeshLevel) -> Vec<MeshCode> {
+    let Some(bbox) = polygon_bbox(vertices) else {
+        return Vec::new();
+    };
+
+    bbox.tile_by_level(level)
+        .into_iter()
+        .filter(|tile| rect_inside_polygon(tile, vertices))
+        .filter_map(|tile| coord_to_mesh(tile.center(), level).ok())
+        .collect()
+}
+
+/// 矩形が多角形の内側に完全に含まれるかを判定する
+///
+/// 4隅がすべて内側にあり、多角形の頂点が矩形の内部（境界を除く）になく、
+/// 多角形の辺が矩形の辺を横切らない場合に真を返します。辺同士が接するだけなら
+/// 内側とみなします。
+fn rect_inside_polygon(rect: &BoundingBox, vertices: &[Coordinate]) -> bool {
+    let corners = rect_corners(rect);
+    if !corners.iter().all(|&c| point_in_polygon(c, vertices)) {
+        return false;
+    }
+
+    let vertex_inside = vertices.iter().any(|v| {
+        v.lat() > rect.min_lat()
+            && v.lat() < rect.max_lat()
+            && v.lon() > rect.min_lon()
+            && v.lon() < rect.max_lon()
+    });
+    if vertex_inside {
+        return false;
+    }
+
+    // 頂点が外にあっても、細い凹みの辺が矩形を貫通している場合がある
+    let mut j = vertices.len() - 1;
+    for i in 0..vertices.len() {
+        for k in 0..4 {
+            let (a, b) = (corners[k], corners[(k + 1) % 4]);
+            if segments_cross(vertices[j], vertices[i], a, b) {
+                return false;
+            }
+        }
+        j = i;
+    }
+
+    true
+}
+
+/// 矩形と多角形が重なるかを判定する（境界での接触を含む）
+fn rect_intersects_polygon(rect: &BoundingBox, vertices: &[Coordinate]) -> bool {
+    let corners = rect_corners(rect);
+
+    if corners.iter().any(|&c| point_in_polygon(c, vertices)) {
+        return true;
+    }
+    if vertices.iter().any(|&v| rect.contains(v)) {
+        return true;
+    }
+
+    let mut j = vertices.len() - 1;
+    for i in 0..vertices.len() {
+        for k in 0..4 {
+            let (a, b) = (corners[k], corners[(k + 1) % 4]);
+            if segments_intersect(vertices[j], vertices[i], a, b) {
+                return true;
+            }
+        }
+        j = i;
+    }
+
+    false
+}
+
+/// 矩形の角を南西、南東、北東、北西の順に返す
+pub(crate) fn rect_corners(rect: &BoundingBox) -> [Coordinate; 4] {
+    [
+        Coordinate::new_unchecked(rect.min_lat(), rect.min_lon()),
+        Coordinate::new_unchecked(rect.min_lat(), rect.max_lon()),
+        Coordinate::new_unchecked(rect.max_lat(), rect.max_lon()),
+        Coordinate::new_unchecked(rect.max_lat(), rect.min_lon()),
+    ]
+}
+
+/// 点`b`が有向線分`o`→`a`のどちら側にあるかを表す外積
+fn cross(o: Coordinate, a: Coordinate, b: Coordinate) -> f64 {
+    (a.lon() - o.lon()) * (b.lat() - o.lat()) - (a.lat() - o.lat()) * (b.lon() - o.lon())
+}
+
+/// 2つの線分が互いの内部で横切るか（端点や線上での接触を除く）を判定する
+fn segments_cross(p1: Coordinate, p2: Coordinate, q1: Coordinate, q2: Coordinate) -> bool {
+    let (d1, d2) = (cross(q1, q2, p1), cross(q1, q2, p2));
+    let (d3, d4) = (cross(p1, p2, q1), cross(p1, p2, q2));
+    ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+}
+
+/// 2つの線分が交差するか（端点での接触を含む）を判定する
+fn segments_intersect(p1: Coordinate, p2: Coordinate, q1: Coordinate, q2: Coordinate) -> bool {
+    let on_segment = |a: Coordinate, b: Coordinate, c: Coordinate| {
+        c.lon() >= a.lon().min(b.lon())
+            && c.lon() <= a.lon().max(b.lon())
+            && c.lat() >= a.lat().min(b.lat())
+            && c.lat() <= a.lat().max(b.lat())
+    };
+
+    if segments_cross(p1, p2, q1, q2) {
+        return true;
+    }
+
+    let d1 = cross(q1, q2, p1);
+    let d2 = cross(q1, q2, p2);
+    let d3 = cross(p1, p2, q1);
+    let d4 = cross(p1, p2, q2);
+    (d1 == 0.0 && on_segment(q1, q2, p1))
+        || (d2 == 0.0 && on_segment(q1, q2, p2))
+        || (d3 == 0.0 && on_segment(p1, p2, q1))
+        || (d4 == 0.0 && on_segment(p1, p2, q2))
+}
+
+/// 多角形を囲む境界ボックスを返す（頂点が3個未満の場合はNone）
+pub(crate) fn polygon_bbox(vertices: &[Coordinate]) -> Option<BoundingBox> {
+    if vertices.len() < 3 {
+        return None;
+    }
+
+    let (mut min_lat, mut max_lat) = (f64::INFINITY, f64::NEG_INFINITY);
+    let (mut min_lon, mut max_lon) = (f64::INFINITY, f64::NEG_INFINITY);
+    for v in vertices {
+        min_lat = min_lat.min(v.lat());
+        max_lat = max_lat.max(v.lat());
+        min_lon = min_lon.min(v.lon());
+        max_lon = max_lon.max(v.lon());
+    }
+
+    Some(BoundingBox::new(
+        Coordinate::new_unchecked(min_lat, min_lon),
+        Coordinate::new_unchecked(max_lat, max_lon),
+    ))
+}
+
+/// 点が多角形の内側にあるかをレイキャスティング法で判定する
+///
+/// 点から東向きに伸ばした半直線と多角形の辺との交差回数が奇数なら内側です。
+pub(crate) fn point_in_polygon(point: Coordinate, vertices: &[Coordinate]) -> bool {
+    let (lat, lon) = (point.lat(), point.lon());
+    let mut inside = false;
+
+    let mut j = vertices.len() - 1;
+    for i in 0..vertices.len() {
+        let (lat_i, lon_i) = (vertices[i].lat(), vertices[i].lon());
+        let (lat_j, lon_j) = (vertices[j].lat(), vertices[j].lon());
+
+        if (lat_i > lat) != (lat_j > lat) {
+            let cross_lon = lon_i + (lat - lat_i) / (lat_j - lat_i) * (lon_j - lon_i);
+            if lon < cross_lon {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::mesh_to_center;
+
+    fn triangle() -> [Coordinate; 3] {
+        [
+            Coordinate::new(35.60, 139.70).unwrap(),
+            Coordinate::new(35.60, 139.80).unwrap(),
+            Coordinate::new(35.70, 139.75).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        let tri = triangle();
+        assert!(point_in_polygon(
+            Coordinate::new_unchecked(35.62, 139.75),
+            &tri
+        ));
+        assert!(!point_in_polygon(
+            Coordinate::new_unchecked(35.69, 139.71),
+            &tri
+        ));
+        assert!(!point_in_polygon(
+            Coordinate::new_unchecked(35.50, 139.75),
+            &tri
+        ));
+    }
+
+    #[test]
+    fn test_mesh_codes_in_polygon_triangle() {
+        let tri = triangle();
+        let meshes = mesh_codes_in_polygon(&tri, MeshLevel::Third);
+
+        // 内部の点を含むメッシュは含まれる
+        let interior =
+            coord_to_mesh(Coordinate::new(35.62, 139.75).unwrap(), MeshLevel::Third).unwrap();
+        assert!(meshes.contains(&interior));
+
+        // 境界ボックス内でも三角形の外（北西の角）のメッシュは含まれない
+        let outside =
+            coord_to_mesh(Coordinate::new(35.69, 139.705).unwrap(), MeshLevel::Third).unwrap();
+        assert!(!meshes.contains(&outside));
+
+        // すべてのメッシュの中心が三角形の内側
+        assert!(meshes
+            .iter()
+            .all(|&m| point_in_polygon(mesh_to_center(m), &tri)));
+    }
+
+    #[test]
+    fn test_mesh_codes_in_polygon_degenerate() {
+        let tri = triangle();
+        assert!(mesh_codes_in_polygon(&tri[..2], MeshLevel::Third).is_empty());
+    }
+
+    #[test]
+    fn test_mesh_codes_covering_polygon_superset() {
+        let tri = triangle();
+        let centered = mesh_codes_in_polygon(&tri, MeshLevel::Third);
+        let covering = mesh_codes_covering_polygon(&tri, MeshLevel::Third);
+
+        assert!(centered.iter().all(|m| covering.contains(m)));
+        // 辺にかかるメッシュの分だけ多い
+        assert!(covering.len() > centered.len());
+
+        // 三角形の頂点はいずれかのメッシュに含まれる
+        for v in tri {
+            assert!(covering
+                .iter()
+                .any(|&m| crate::convert::mesh_to_bounds(m).contains(v)));
+        }
+    }
+
+    #[test]
+    fn test_mesh_codes_fully_inside_polygon_subset() {
+        let tri = triangle();
+        let inside = mesh_codes_fully_inside_polygon(&tri, MeshLevel::Third);
+        let centered = mesh_codes_in_polygon(&tri, MeshLevel::Third);
+
+        assert!(!inside.is_empty());
+        assert!(inside.iter().all(|m| centered.contains(m)));
+        // 中心は内側でも辺にかかるメッシュは除かれる
+        assert!(inside.len() < centered.len());
+
+        for &m in &inside {
+            let bounds = crate::convert::mesh_to_bounds(m);
+            assert!(rect_corners(&bounds)
+                .iter()
+                .all(|&c| point_in_polygon(c, &tri)));
+        }
+    }
+
+    #[test]
+    fn test_mesh_codes_fully_inside_polygon_excludes_notch() {
+        // 北側から凹みが入り込んだ多角形。凹みの頂点を含むメッシュは4隅が内側でも除かれる
+        let c = |lat, lon| Coordinate::new(lat, lon).unwrap();
+        let notch_tip = c(35.655, 139.755);
+        let polygon = [
+            c(35.60, 139.70),
+            c(35.60, 139.80),
+            c(35.70, 139.80),
+            c(35.70, 139.7551),
+            notch_tip,
+            c(35.70, 139.7549),
+            c(35.70, 139.70),
+        ];
+        let tip_mesh = coord_to_mesh(notch_tip, MeshLevel::Third).unwrap();
+        let inside = mesh_codes_fully_inside_polygon(&polygon, MeshLevel::Third);
+        assert!(!inside.contains(&tip_mesh));
+    }
+
+    #[test]
+    fn test_mesh_codes_fully_inside_polygon_excludes_channel_through_mesh() {
+        // 北側から細い水路が入り込み、3次メッシュ53394600（北緯35.65〜35.6583度、
+        // 東経139.75〜139.7625度）の隅の間を南北に貫通して、その南で終わる多角形。
+        // メッシュの4隅は内側で、メッシュ内に頂点もないが、水路の辺がメッシュを横切る
+        let c = |lat, lon| Coordinate::new(lat, lon).unwrap();
+        let polygon = [
+            c(35.60, 139.70),
+            c(35.60, 139.80),
+            c(35.70, 139.80),
+            c(35.70, 139.7557),
+            c(35.625, 139.7557),
+            c(35.625, 139.7553),
+            c(35.70, 139.7553),
+            c(35.70, 139.70),
+        ];
+        let crossed = MeshCode::from_str("53394600").unwrap();
+        let bounds = crate::convert::mesh_to_bounds(crossed);
+        assert!(rect_corners(&bounds)
+            .iter()
+            .all(|&corner| point_in_polygon(corner, &polygon)));
+        assert!(!polygon.iter().any(|&v| bounds.contains(v)));
+
+        let inside = mesh_codes_fully_inside_polygon(&polygon, MeshLevel::Third);
+        assert!(!inside.contains(&crossed));
+        // 水路から離れたメッシュは含まれる
+        let away = coord_to_mesh(c(35.654, 139.72), MeshLevel::Third).unwrap();
+        assert!(inside.contains(&away));
+    }
+
+    #[test]
+    fn test_segments_cross_excludes_touching() {
+        let c = Coordinate::new_unchecked;
+        assert!(segments_cross(
+            c(0.0, 0.0),
+            c(1.0, 1.0),
+            c(0.0, 1.0),
+            c(1.0, 0.0)
+        ));
+        // 端点で接するだけ・同一直線上で重なるだけの場合は横切らない
+        assert!(!segments_cross(
+            c(0.0, 0.0),
+            c(1.0, 0.0),
+            c(1.0, 0.0),
+            c(1.0, 1.0)
+        ));
+        assert!(!segments_cross(
+            c(0.0, 0.0),
+            c(0.0, 2.0),
+            c(0.0, 1.0),
+            c(0.0, 3.0)
+        ));
+    }
+
+    #[test]
+    fn test_segments_intersect() {
+        let c = Coordinate::new_unchecked;
+        assert!(segments_intersect(
+            c(0.0, 0.0),
+            c(1.0, 1.0),
+            c(0.0, 1.0),
+            c(1.0, 0.0)
+        ));
+        assert!(!segments_intersect(
+            c(0.0, 0.0),
+            c(1.0, 0.0),
+            c(0.0, 1.0),
+            c(1.0, 1.0)
+        ));
+    }
+}
@@ -1,19 +1,34 @@
-use crate::convert::mesh_to_center;
+use crate::convert::{mesh_to_bounds, mesh_to_center};
 use crate::spatial::range::MeshCodeIterator;
-use crate::types::{BoundingBox, Coordinate, MeshCode, MeshLevel};
-use crate::utils::distance::{calculate_bbox_offsets, haversine_distance};
+use crate::types::{BoundingBox, Coordinate, MeshCode, MeshLevel, JAPAN_BOUNDS};
+use crate::utils::distance::{
+    distance_to_mesh_edge, haversine_distance_precomputed, haversine_precompute,
+    EARTH_RADIUS_METERS,
+};
+use crate::utils::math;
 
 /// 半径検索でメッシュコードを遅延評価で列挙するイテレータ
 ///
 /// 指定座標からの距離が指定半径以内のメッシュコードを列挙します。
 /// 内部的にはBoundingBoxで範囲を絞り込み、Haversine公式で実距離を判定します。
+///
+/// # 順序
+///
+/// メッシュは[`MeshCodeIterator`]と同じ格子順に返されます。南の行から北の行へ進み、
+/// 各行の中は西から東の順です。このため、返されるメッシュの中心緯度は
+/// 単調非減少で、中心緯度が等しいメッシュの間では中心経度が増加します。
+/// この順序は保証されており、利用側で並べ替えずに依存できます。
 pub struct MeshCodeRadiusIterator {
     bbox_iter: MeshCodeIterator,
     center: Coordinate,
+    // 中心座標の緯度（ラジアン）とその余弦
+    center_trig: (f64, f64),
     radius_meters: f64,
     // 半径0の場合の中心メッシュ（遅延初期化）
     center_mesh_for_zero_radius: Option<Option<MeshCode>>,
     level: MeshLevel,
+    // 直前の行の緯度と、その行で円と重なり得る経度の範囲
+    row_window: Option<(f64, (f64, f64))>,
 }
 
 impl MeshCodeRadiusIterator {
@@ -30,39 +45,33 @@ impl MeshCodeRadiusIterator {
             return MeshCodeRadiusIterator {
                 bbox_iter: MeshCodeIterator::new(empty_bbox, level),
                 center,
+                center_trig: haversine_precompute(center),
                 radius_meters: 0.0,
                 center_mesh_for_zero_radius: Some(None),
                 level,
+                row_window: None,
             };
         }
 
-        // 半径からBoundingBoxを作成
-        let (lat_offset, lon_offset) = if radius_meters == 0.0 {
-            // 半径0の場合は空のBoundingBoxで良い（特殊処理で対応）
-            (0.0, 0.0)
-        } else {
-            calculate_bbox_offsets(center, radius_meters)
-        };
-
-        let min_lat = (center.lat() - lat_offset).max(20.0);
-        let max_lat = (center.lat() + lat_offset).min(46.0);
-        let min_lon = (center.lon() - lon_offset).max(122.0);
-        let max_lon = (center.lon() + lon_offset).min(154.0);
-
-        let sw = Coordinate::new_unchecked(min_lat, min_lon);
-        let ne = Coordinate::new_unchecked(max_lat, max_lon);
-        let bbox = BoundingBox::new(sw, ne);
+        let bbox = radius_bbox(center, radius_meters);
 
         MeshCodeRadiusIterator {
             bbox_iter: MeshCodeIterator::new(bbox, level),
             center,
+            center_trig: haversine_precompute(center),
             radius_meters,
             center_mesh_for_zero_radius: None,
             level,
+            row_window: None,
         }
     }
 }
 
+/// 半径検索の候補範囲となるBoundingBoxを作成する（日本の範囲にクランプ）
+fn radius_bbox(center: Coordinate, radius_meters: f64) -> BoundingBox {
+    BoundingBox::from_center_meters(center, radius_meters, radius_meters)
+}
+
 impl Iterator for MeshCodeRadiusIterator {
     type Item = MeshCode;
 
@@ -80,10 +89,23 @@ impl Iterator for MeshCodeRadiusIterator {
         }
 
         // 通常の半径検索
+        // 各行で円と重なり得る経度の範囲だけを変換・距離判定の対象にする
+        let (center, radius_meters, level) = (self.center, self.radius_meters, self.level);
+        let row_window = &mut self.row_window;
+        let mut lon_window = |row_lat: f64| match *row_window {
+            Some((lat, window)) if lat == row_lat => window,
+            _ => {
+                let window = row_lon_window(center, radius_meters, row_lat, level);
+                *row_window = Some((row_lat, window));
+                window
+            }
+        };
+
         loop {
-            let mesh = self.bbox_iter.next()?;
+            let mesh = self.bbox_iter.next_within(&mut lon_window)?;
             let mesh_center = mesh_to_center(mesh);
-            let distance = haversine_distance(self.center, mesh_center);
+            let distance =
+                haversine_distance_precomputed(self.center, self.center_trig, mesh_center);
 
             if distance <= self.radius_meters {
                 return Some(mesh);
@@ -93,6 +115,69 @@ impl Iterator for MeshCodeRadiusIterator {
     }
 }
 
+/// 緯度`row_lat`の走査行で、中心が半径内に入り得るメッシュを含む経度の範囲を求める
+///
+/// 行内の位置を含むメッシュの中心は、緯度・経度ともにその位置からメッシュ半分以内に
+/// あります。その緯度範囲で中心からの距離が半径以内になる最大の経度差を求め、
+/// 経度方向にもメッシュ半分の余裕（と誤差分）を持たせた範囲を返します。この範囲外の位置から
+/// 得られるメッシュは距離判定を必ず満たさないため、読み飛ばしても結果は変わりません。
+fn row_lon_window(
+    center: Coordinate,
+    radius_meters: f64,
+    row_lat: f64,
+    level: MeshLevel,
+) -> (f64, f64) {
+    const FULL: (f64, f64) = (f64::NEG_INFINITY, f64::INFINITY);
+
+    let delta = radius_meters / EARTH_RADIUS_METERS;
+    if delta >= core::f64::consts::PI {
+        return FULL;
+    }
+
+    // メッシュ半分に浮動小数点誤差を吸収する余裕を加える
+    let lat_margin = level.lat_size_degrees() * 0.5 + 1e-9;
+    let lon_margin = level.lon_size_degrees() * 0.5 + 1e-9;
+    let lat_lo = row_lat - lat_margin;
+    let lat_hi = row_lat + lat_margin;
+
+    // 行内のメッシュ中心と円の中心との緯度差の最小値
+    let min_dlat = if (lat_lo..=lat_hi).contains(&center.lat()) {
+        0.0
+    } else {
+        (lat_lo - center.lat())
+            .abs()
+            .min((lat_hi - center.lat()).abs())
+            .to_radians()
+    };
+
+    // Haversine公式: sin²(δ/2) = sin²(Δφ/2) + cosφ1·cosφ2·sin²(Δλ/2)
+    let sin_half_delta = math::sin(delta / 2.0);
+    let sin_half_dlat = math::sin(min_dlat / 2.0);
+    let remaining = sin_half_delta * sin_half_delta - sin_half_dlat * sin_half_dlat;
+    if remaining < 0.0 {
+        return (f64::INFINITY, f64::NEG_INFINITY);
+    }
+
+    // 経度差が最大になるのはcosφ2が最小（最も高緯度）のとき
+    let max_abs_lat = lat_lo.abs().max(lat_hi.abs()).min(90.0);
+    let cos_product = math::cos(center.lat().to_radians()) * math::cos(max_abs_lat.to_radians());
+    if cos_product <= 0.0 || remaining >= cos_product {
+        return FULL;
+    }
+
+    let sin_half_dlon = math::sqrt(remaining / cos_product);
+    let half_dlon = math::atan2(
+        sin_half_dlon,
+        math::sqrt(1.0 - sin_half_dlon * sin_half_dlon),
+    );
+    let dlon = (2.0 * half_dlon).to_degrees() + lon_margin;
+    (center.lon() - dlon, center.lon() + dlon)
+}
+
+// 半径0の特殊処理は中心メッシュを1度だけ返し、通常の検索は終端後もNoneを返し続ける
+// MeshCodeIteratorに委ねるため、終端後は常にNoneを返す
+impl core::iter::FusedIterator for MeshCodeRadiusIterator {}
+
 /// 指定座標から指定距離内のメッシュコードをイテレータで取得する
 ///
 /// 指定座標を中心として、指定半径以内のメッシュコードを列挙します。
@@ -156,10 +241,333 @@ pub fn mesh_codes_in_radius_from_mesh(
     MeshCodeRadiusIterator::new(center, radius_meters, level)
 }
 
+/// メッシュの中心から指定距離内に一部でもかかるメッシュコードを取得する
+///
+/// [`mesh_codes_in_radius_from_mesh`]は各メッシュの中心座標との距離で判定するため、
+/// 半径が小さいと本体の一部が範囲内にあるメッシュも除外されます。この関数は
+/// [`distance_to_mesh_edge`]で求めた、中心からメッシュ矩形上の最も近い点までの距離で
+/// 判定し、円と重なるメッシュをすべて返します。中心のメッシュは常に含まれ、
+/// 結果は[`mesh_codes_in_radius_from_mesh`]の結果を必ず含みます。
+/// 半径が負の場合は空になります。
+///
+/// # 引数
+/// * `mesh` - 中心となるメッシュコード
+/// * `radius_meters` - 検索半径（メートル）
+///
+/// # 戻り値
+/// メッシュコードを列挙するイテレータ（同じレベル）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+///
+/// // 半径800mでは、中心同士の距離で判定すると中心メッシュしか含まれないが、
+/// // 重なりで判定すると周囲8メッシュも含まれる
+/// assert_eq!(mesh_codes_in_radius_from_mesh(mesh, 800.0).count(), 1);
+/// assert_eq!(mesh_codes_in_radius_from_mesh_overlap(mesh, 800.0).count(), 9);
+/// ```
+pub fn mesh_codes_in_radius_from_mesh_overlap(
+    mesh: MeshCode,
+    radius_meters: f64,
+) -> impl Iterator<Item = MeshCode> {
+    let center = mesh_to_center(mesh);
+    let level = mesh.level();
+
+    // 走査位置がメッシュの端で取りこぼさないよう、候補範囲をメッシュ1つ分広げる
+    let bbox = radius_bbox(center, radius_meters.max(0.0));
+    let bbox = BoundingBox::new(
+        Coordinate::new_unchecked(
+            (bbox.min_lat() - level.lat_size_degrees()).max(JAPAN_BOUNDS.min_lat()),
+            (bbox.min_lon() - level.lon_size_degrees()).max(JAPAN_BOUNDS.min_lon()),
+        ),
+        Coordinate::new_unchecked(
+            (bbox.max_lat() + level.lat_size_degrees()).min(JAPAN_BOUNDS.max_lat()),
+            (bbox.max_lon() + level.lon_size_degrees()).min(JAPAN_BOUNDS.max_lon()),
+        ),
+    );
+
+    MeshCodeIterator::new(bbox, level)
+        .filter(move |&m| radius_meters >= 0.0 && distance_to_mesh_edge(center, m) <= radius_meters)
+}
+
+/// 半径検索の結果が真の円をどの程度近似しているかを返す
+///
+/// [`mesh_codes_in_radius`]で得られるメッシュの面積
+/// （[`MeshCode::area_square_meters`]）の合計を、円の面積（πr²）で割った値です。
+/// 1.0に近いほど、そのメッシュレベルで円を精度よく近似できていることを表します。
+/// 半径が0以下の場合は0.0を返します。
+///
+/// # 引数
+/// * `center` - 中心座標
+/// * `radius_meters` - 検索半径（メートル）
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// メッシュの合計面積と円の面積の比
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let ratio = radius_coverage_ratio(tokyo, 5000.0, MeshLevel::Fifth);
+/// assert!((ratio - 1.0).abs() < 0.05);
+/// ```
+pub fn radius_coverage_ratio(center: Coordinate, radius_meters: f64, level: MeshLevel) -> f64 {
+    if radius_meters <= 0.0 {
+        return 0.0;
+    }
+
+    let total = mesh_codes_in_radius(center, radius_meters, level)
+        .fold(0.0, |acc, mesh| acc + mesh.area_square_meters());
+    total / (core::f64::consts::PI * radius_meters * radius_meters)
+}
+
+/// [`mesh_circle_overlap_fraction`]でメッシュの1辺あたりに取る標本点の数
+const OVERLAP_SAMPLES_PER_SIDE: u32 = 16;
+
+/// メッシュの面積のうち円の内側にある割合を返す
+///
+/// メッシュの矩形を`16 × 16`の小区画に分け、各区画の中心と`center`との
+/// Haversine距離が`radius_meters`以内かどうかを数えて割合を近似します。
+/// 半径検索の結果のうち円の縁にかかるメッシュを、重なる面積に応じて
+/// 重み付けする用途に使います。精度は1/256程度です。
+/// 半径が0以下の場合は0.0を返します。
+///
+/// # 引数
+/// * `mesh` - 対象のメッシュコード
+/// * `center` - 円の中心座標
+/// * `radius_meters` - 円の半径（メートル）
+///
+/// # 戻り値
+/// 円の内側にある面積の割合（0.0〜1.0）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let bounds = mesh_to_bounds(mesh);
+///
+/// // メッシュの南西端を中心とする半径400mの円は、その4分の1（約12.6万㎡）が
+/// // メッシュ（約106万㎡）に重なるため、割合は約0.12になる
+/// let fraction = mesh_circle_overlap_fraction(mesh, bounds.south_west(), 400.0);
+/// assert!((fraction - 0.12).abs() < 0.02);
+/// ```
+pub fn mesh_circle_overlap_fraction(mesh: MeshCode, center: Coordinate, radius_meters: f64) -> f64 {
+    if radius_meters <= 0.0 {
+        return 0.0;
+    }
+
+    let bounds = mesh_to_bounds(mesh);
+    let n = OVERLAP_SAMPLES_PER_SIDE;
+    let lat_step = (bounds.max_lat() - bounds.min_lat()) / n as f64;
+    let lon_step = (bounds.max_lon() - bounds.min_lon()) / n as f64;
+    let center_trig = haversine_precompute(center);
+
+    let inside = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .filter(|&(i, j)| {
+            let sample = Coordinate::new_unchecked(
+                bounds.min_lat() + (i as f64 + 0.5) * lat_step,
+                bounds.min_lon() + (j as f64 + 0.5) * lon_step,
+            );
+            haversine_distance_precomputed(center, center_trig, sample) <= radius_meters
+        })
+        .count();
+    inside as f64 / (n * n) as f64
+}
+
+/// 指定座標から指定距離内のメッシュコードを並列に取得する（`rayon`フィーチャー）
+///
+/// [`mesh_codes_in_radius`]と同じ候補範囲のメッシュを列挙し、Haversine距離による
+/// 判定を`rayon`で並列に行います。細かいレベルで大きな半径を検索する場合など、
+/// 距離判定がボトルネックになるときに使用します。
+///
+/// 結果は[`mesh_codes_in_radius`]と同じメッシュを同じ順序で含みます。
+///
+/// # 引数
+/// * `center` - 中心座標
+/// * `radius_meters` - 検索半径（メートル）
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// 半径内のメッシュコードのベクター
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let meshes = mesh_codes_in_radius_par(tokyo, 1000.0, MeshLevel::Third);
+/// assert!(!meshes.is_empty());
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn mesh_codes_in_radius_par(
+    center: Coordinate,
+    radius_meters: f64,
+    level: MeshLevel,
+) -> alloc::vec::Vec<MeshCode> {
+    use rayon::prelude::*;
+
+    // 半径0・負の半径は候補が高々1つなので逐次版に任せる
+    if radius_meters <= 0.0 {
+        return mesh_codes_in_radius(center, radius_meters, level).collect();
+    }
+
+    let candidates: alloc::vec::Vec<MeshCode> =
+        MeshCodeIterator::new(radius_bbox(center, radius_meters), level).collect();
+
+    let center_trig = haversine_precompute(center);
+    candidates
+        .into_par_iter()
+        .filter(|&mesh| {
+            haversine_distance_precomputed(center, center_trig, mesh_to_center(mesh))
+                <= radius_meters
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::convert::coord_to_mesh;
+    use crate::utils::distance::haversine_distance;
+
+    #[test]
+    fn test_radius_from_mesh_overlap_is_superset() {
+        let meshes = [
+            MeshCode::from_str("53394611").unwrap(),
+            MeshCode::from_str("533946113").unwrap(),
+            MeshCode::parse("5339461155", Some(MeshLevel::Fifth)).unwrap(),
+        ];
+        for mesh in meshes {
+            for radius in [0.0, 50.0, 300.0, 600.0, 1500.0] {
+                let overlap: Vec<MeshCode> =
+                    mesh_codes_in_radius_from_mesh_overlap(mesh, radius).collect();
+                assert!(overlap.contains(&mesh));
+                for m in mesh_codes_in_radius_from_mesh(mesh, radius) {
+                    assert!(overlap.contains(&m), "{mesh} r={radius}: {m}");
+                }
+                let center = mesh_to_center(mesh);
+                assert!(overlap
+                    .iter()
+                    .all(|&m| crate::utils::distance::distance_to_mesh_edge(center, m) <= radius));
+            }
+        }
+
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        assert_eq!(mesh_codes_in_radius_from_mesh_overlap(mesh, 0.0).count(), 1);
+        assert_eq!(
+            mesh_codes_in_radius_from_mesh_overlap(mesh, -1.0).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_mesh_circle_overlap_fraction() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let center = mesh_to_center(mesh);
+
+        // 3次メッシュ（約1km四方）は半径2kmの円に完全に含まれる
+        assert!((mesh_circle_overlap_fraction(mesh, center, 2000.0) - 1.0).abs() < 1e-9);
+
+        // 10km離れた円とは重ならない
+        let far = MeshCode::from_str("53395611").unwrap();
+        assert_eq!(mesh_circle_overlap_fraction(far, center, 2000.0), 0.0);
+
+        // 南西端を中心とする円は一部だけ重なる
+        let partial = mesh_circle_overlap_fraction(mesh, mesh_to_bounds(mesh).south_west(), 700.0);
+        assert!(partial > 0.1 && partial < 0.9);
+
+        assert_eq!(mesh_circle_overlap_fraction(mesh, center, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_radius_iterator_grid_order() {
+        let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+        for (radius, level) in [
+            (3000.0, MeshLevel::Third),
+            (800.0, MeshLevel::FourthQuarter),
+            (500.0, MeshLevel::Fifth),
+        ] {
+            let centers: Vec<Coordinate> = mesh_codes_in_radius(tokyo, radius, level)
+                .map(mesh_to_center)
+                .collect();
+            assert!(centers.len() > 9);
+
+            for pair in centers.windows(2) {
+                let (a, b) = (pair[0], pair[1]);
+                assert!(a.lat() <= b.lat(), "latitude decreased at level {level:?}");
+                if a.lat() == b.lat() {
+                    assert!(a.lon() < b.lon(), "longitude not increasing within a row");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_row_pruning_matches_unpruned_search() {
+        let centers = [
+            Coordinate::new(35.6812, 139.7671).unwrap(),
+            Coordinate::new(43.0687, 141.3508).unwrap(),
+            Coordinate::new(26.2124, 127.6809).unwrap(),
+        ];
+        let cases = [
+            (MeshLevel::Third, 500.0),
+            (MeshLevel::Third, 3000.0),
+            (MeshLevel::Third, 20000.0),
+            (MeshLevel::Second, 50000.0),
+            (MeshLevel::Fifth, 1500.0),
+            (MeshLevel::FourthEighth, 800.0),
+        ];
+
+        for center in centers {
+            for (level, radius) in cases {
+                let expected: Vec<MeshCode> =
+                    MeshCodeIterator::new(radius_bbox(center, radius), level)
+                        .filter(|&mesh| haversine_distance(center, mesh_to_center(mesh)) <= radius)
+                        .collect();
+                let actual: Vec<MeshCode> = mesh_codes_in_radius(center, radius, level).collect();
+                assert_eq!(actual, expected, "{center:?} {level:?} {radius}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_radius_iterator_keeps_returning_none() {
+        let center = Coordinate::new(35.6812, 139.7671).unwrap();
+        for radius in [-1.0, 0.0, 1500.0] {
+            let mut iter = mesh_codes_in_radius(center, radius, MeshLevel::Third);
+            let count = iter.by_ref().count();
+            assert_eq!(count > 0, radius >= 0.0);
+            for _ in 0..5 {
+                assert_eq!(iter.next(), None, "radius {radius}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_radius_coverage_ratio_improves_with_finer_level() {
+        let center = Coordinate::new(35.6812, 139.7671).unwrap();
+        let radius = 10000.0;
+
+        let coarse = radius_coverage_ratio(center, radius, MeshLevel::Second);
+        let medium = radius_coverage_ratio(center, radius, MeshLevel::Third);
+        let fine = radius_coverage_ratio(center, radius, MeshLevel::Fifth);
+
+        assert!((fine - 1.0).abs() < (medium - 1.0).abs());
+        assert!((medium - 1.0).abs() < (coarse - 1.0).abs());
+        assert!((fine - 1.0).abs() < 0.01);
+        assert_eq!(radius_coverage_ratio(center, 0.0, MeshLevel::Third), 0.0);
+    }
 
     #[test]
     fn test_mesh_codes_in_radius_zero() {
@@ -250,4 +658,22 @@ mod tests {
         assert!(second_level.iter().all(|m| m.level() == MeshLevel::Second));
         assert!(third_level.iter().all(|m| m.level() == MeshLevel::Third));
     }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_mesh_codes_in_radius_par_matches_sequential() {
+        let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+
+        for (radius, level) in [
+            (0.0, MeshLevel::Third),
+            (2000.0, MeshLevel::Third),
+            (1500.0, MeshLevel::Fifth),
+        ] {
+            let mut sequential: Vec<_> = mesh_codes_in_radius(tokyo, radius, level).collect();
+            let mut parallel = mesh_codes_in_radius_par(tokyo, radius, level);
+            sequential.sort();
+            parallel.sort();
+            assert_eq!(parallel, sequential);
+        }
+    }
 }
@@ -1,12 +1,17 @@
-use crate::convert::coord_to_mesh;
-use crate::types::{BoundingBox, MeshCode, MeshLevel};
+use crate::convert::MeshPrefixCache;
+use crate::types::{BoundingBox, Coordinate, MeshCode, MeshLevel};
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
 
 /// 範囲内のメッシュコードを遅延評価で列挙するイテレータ
 ///
 /// 大量のメッシュコードを扱う場合でも、メモリ効率的に処理できます。
+/// 走査中は1次・2次メッシュの接頭辞をキャッシュし、親メッシュの境界を
+/// 越えたときのみ再計算します。
 pub struct MeshCodeIterator {
     bbox: BoundingBox,
     level: MeshLevel,
+    cache: MeshPrefixCache,
     current_lat: f64,
     current_lon: f64,
     lat_step: f64,
@@ -21,6 +26,7 @@ impl MeshCodeIterator {
         MeshCodeIterator {
             bbox,
             level,
+            cache: MeshPrefixCache::new(),
             current_lat: bbox.min_lat(),
             current_lon: bbox.min_lon(),
             lat_step,
@@ -29,19 +35,39 @@ impl MeshCodeIterator {
     }
 }
 
-impl Iterator for MeshCodeIterator {
-    type Item = MeshCode;
+impl MeshCodeIterator {
+    /// 各行で経度の範囲を絞り込みながら次のメッシュコードを返す
+    ///
+    /// `lon_window`は行の緯度を受け取り、その行で変換を行う経度の範囲（両端を含む）を
+    /// 返します。範囲外の位置は座標変換を行わずに読み飛ばし、範囲の東端を越えた時点で
+    /// 次の行へ進みます。走査する位置自体は[`Iterator::next`]と同じです。
+    pub(crate) fn next_within<F>(&mut self, lon_window: F) -> Option<MeshCode>
+    where
+        F: FnMut(f64) -> (f64, f64),
+    {
+        self.next_converted(lon_window, MeshPrefixCache::convert)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// 走査位置を`convert`で変換し、変換に成功した次の結果を返す
+    ///
+    /// `lon_window`の扱いは[`MeshCodeIterator::next_within`]と同じです。
+    fn next_converted<T, F, C>(&mut self, mut lon_window: F, mut convert: C) -> Option<T>
+    where
+        F: FnMut(f64) -> (f64, f64),
+        C: FnMut(&mut MeshPrefixCache, Coordinate, MeshLevel) -> crate::error::Result<T>,
+    {
         while self.current_lat <= self.bbox.max_lat() {
-            while self.current_lon <= self.bbox.max_lon() {
-                let coord =
-                    crate::types::Coordinate::new_unchecked(self.current_lat, self.current_lon);
-
+            let (min_lon, max_lon) = lon_window(self.current_lat);
+            while self.current_lon <= self.bbox.max_lon() && self.current_lon <= max_lon {
+                let lon = self.current_lon;
                 self.current_lon += self.lon_step;
+                if lon < min_lon {
+                    continue;
+                }
 
-                if let Ok(mesh) = coord_to_mesh(coord, self.level) {
-                    return Some(mesh);
+                let coord = Coordinate::new_unchecked(self.current_lat, lon);
+                if let Ok(item) = convert(&mut self.cache, coord, self.level) {
+                    return Some(item);
                 }
             }
 
@@ -53,6 +79,17 @@ impl Iterator for MeshCodeIterator {
     }
 }
 
+impl Iterator for MeshCodeIterator {
+    type Item = MeshCode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_within(|_| (f64::NEG_INFINITY, f64::INFINITY))
+    }
+}
+
+// 走査位置は北端を越えた後も戻らないため、終端後は常にNoneを返す
+impl core::iter::FusedIterator for MeshCodeIterator {}
+
 /// 指定された境界ボックス内のメッシュコードをイテレータで取得する
 ///
 /// # 引数
@@ -78,10 +115,484 @@ pub fn mesh_codes_in_bbox(bbox: BoundingBox, level: MeshLevel) -> MeshCodeIterat
     MeshCodeIterator::new(bbox, level)
 }
 
+/// 指定された境界ボックス内のメッシュコードを中心座標と組にして取得する
+///
+/// [`mesh_codes_in_bbox`]と同じ順にメッシュを列挙し、中心座標と組み合わせて返します。
+/// 中心座標は走査中の変換で求めた南西端から計算するため、メッシュコードを
+/// [`crate::mesh_to_center`]で再度解析するより高速で、結果は[`crate::mesh_to_center`]と一致します。
+///
+/// # 引数
+/// * `bbox` - 検索範囲を表す境界ボックス
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// （メッシュコード、中心座標）の組を列挙するイテレータ
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let sw = Coordinate::new(35.6, 139.7).unwrap();
+/// let ne = Coordinate::new(35.7, 139.8).unwrap();
+/// let bbox = BoundingBox::new(sw, ne);
+///
+/// for (mesh, center) in mesh_codes_in_bbox_with_centers(bbox, MeshLevel::Third) {
+///     assert_eq!(center, mesh_to_center(mesh));
+/// }
+/// ```
+pub fn mesh_codes_in_bbox_with_centers(
+    bbox: BoundingBox,
+    level: MeshLevel,
+) -> impl Iterator<Item = (MeshCode, Coordinate)> {
+    let mut iter = MeshCodeIterator::new(bbox, level);
+    core::iter::from_fn(move || {
+        iter.next_converted(
+            |_| (f64::NEG_INFINITY, f64::INFINITY),
+            MeshPrefixCache::convert_with_center,
+        )
+    })
+}
+
+/// 境界ボックス内のメッシュを格子状に並べたときの行数と列数を返す
+///
+/// [`MeshCodeIterator`]と同じく南西端からメッシュサイズずつ位置を進めた場合の
+/// 走査位置の数を、緯度方向（行）・経度方向（列）それぞれについて数えます。
+/// 2次元配列を事前に確保する用途に使います。範囲がすべて日本の範囲内であれば、
+/// `行数 * 列数`は[`mesh_codes_in_bbox`]で得られるメッシュの数と一致します。
+///
+/// # 引数
+/// * `bbox` - 検索範囲を表す境界ボックス
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// `(行数, 列数)`
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let sw = Coordinate::new(35.6, 139.7).unwrap();
+/// let ne = Coordinate::new(35.7, 139.8).unwrap();
+/// let bbox = BoundingBox::new(sw, ne);
+///
+/// let (rows, cols) = bbox_grid_dimensions(&bbox, MeshLevel::Third);
+/// assert_eq!(rows * cols, mesh_codes_in_bbox(bbox, MeshLevel::Third).count());
+/// ```
+pub fn bbox_grid_dimensions(bbox: &BoundingBox, level: MeshLevel) -> (usize, usize) {
+    let rows = step_count(bbox.min_lat(), bbox.max_lat(), level.lat_size_degrees());
+    let cols = step_count(bbox.min_lon(), bbox.max_lon(), level.lon_size_degrees());
+    (rows, cols)
+}
+
+/// `min`から`step`ずつ加算して`max`以下に収まる位置の数を数える
+///
+/// [`MeshCodeIterator`]と同じ加算を繰り返すため、浮動小数点誤差も含めて
+/// 走査位置の数が一致します。
+fn step_count(min: f64, max: f64, step: f64) -> usize {
+    let mut count = 0;
+    let mut value = min;
+    while value <= max {
+        count += 1;
+        value += step;
+    }
+    count
+}
+
+/// 境界ボックス内のメッシュコードを行ごとに分けた2次元のベクターで取得する
+///
+/// 外側のベクターは南の行から北の行へ、各行は西から東の順に並び、
+/// [`mesh_codes_in_bbox`]の結果を[`bbox_grid_dimensions`]の列数ごとに区切ったものと
+/// 同じです。日本の範囲外の位置は行から除かれるため、その場合は行の長さが
+/// 列数より短くなります。
+///
+/// # 引数
+/// * `bbox` - 検索範囲を表す境界ボックス
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// メッシュコードの2次元ベクター（`grid[行][列]`）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// // 3次メッシュ5×5個分の範囲
+/// let sw = mesh_to_center(MeshCode::from_str("53394611").unwrap());
+/// let ne = mesh_to_center(MeshCode::from_str("53394655").unwrap());
+/// let bbox = BoundingBox::new(sw, ne);
+///
+/// let grid = mesh_codes_in_bbox_grid(bbox, MeshLevel::Third);
+/// assert_eq!(grid.len(), 5);
+/// assert_eq!(grid[0][0].as_string(), "53394611");
+/// assert_eq!(grid[4][4].as_string(), "53394655");
+/// ```
+pub fn mesh_codes_in_bbox_grid(bbox: BoundingBox, level: MeshLevel) -> Vec<Vec<MeshCode>> {
+    let (rows, cols) = bbox_grid_dimensions(&bbox, level);
+    let mut grid: Vec<Vec<MeshCode>> = (0..rows).map(|_| Vec::with_capacity(cols)).collect();
+    for (row, _, mesh) in grid_cells(&bbox, level, rows, cols) {
+        grid[row].push(mesh);
+    }
+    grid
+}
+
+/// 境界ボックスを走査し、`(南からの行番号, 列番号, メッシュコード)`を走査順に返す
+///
+/// [`MeshCodeIterator`]と同じく南西端から緯度・経度を加算して走査位置を求めます。
+/// 日本の範囲外で変換できない位置は結果に含まれません。
+fn grid_cells(
+    bbox: &BoundingBox,
+    level: MeshLevel,
+    rows: usize,
+    cols: usize,
+) -> Vec<(usize, usize, MeshCode)> {
+    let lat_step = level.lat_size_degrees();
+    let lon_step = level.lon_size_degrees();
+    let mut cache = MeshPrefixCache::new();
+
+    let mut cells = Vec::with_capacity(rows * cols);
+    let mut lat = bbox.min_lat();
+    for row in 0..rows {
+        let mut lon = bbox.min_lon();
+        for col in 0..cols {
+            if let Ok(mesh) = cache.convert(Coordinate::new_unchecked(lat, lon), level) {
+                cells.push((row, col, mesh));
+            }
+            lon += lon_step;
+        }
+        lat += lat_step;
+    }
+    cells
+}
+
+/// 境界ボックス内のメッシュコードを行・列番号と組にしてラスター順に取得する
+///
+/// ラスター画像（GeoTIFFなど）への書き出し向けに、行0を北端とし、
+/// 北の行から南の行へ、各行は西から東の順（行優先）で`(行, 列, メッシュコード)`を返します。
+/// 行数・列数は[`bbox_grid_dimensions`]と同じで、`buffer[行 * 列数 + 列]`のように
+/// 2次元のバッファへそのまま書き込めます。日本の範囲外で変換できないセルは
+/// 結果に含まれません。
+///
+/// [`mesh_codes_in_bbox_grid`]は南の行から並べる点が異なります。
+///
+/// # 引数
+/// * `bbox` - 検索範囲を表す境界ボックス
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// `(行, 列, メッシュコード)`のベクター
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// // 3次メッシュ5×5個分の範囲
+/// let sw = mesh_to_center(MeshCode::from_str("53394611").unwrap());
+/// let ne = mesh_to_center(MeshCode::from_str("53394655").unwrap());
+/// let bbox = BoundingBox::new(sw, ne);
+///
+/// let cells = bbox_mesh_grid(bbox, MeshLevel::Third);
+/// assert_eq!(cells.len(), 25);
+/// assert_eq!(cells[0], (0, 0, MeshCode::from_str("53394651").unwrap()));
+/// assert_eq!(cells[24], (4, 4, MeshCode::from_str("53394615").unwrap()));
+/// ```
+pub fn bbox_mesh_grid(bbox: BoundingBox, level: MeshLevel) -> Vec<(usize, usize, MeshCode)> {
+    let (rows, cols) = bbox_grid_dimensions(&bbox, level);
+    let mut cells = grid_cells(&bbox, level, rows, cols);
+
+    // 南の行から並んだ走査結果を、行番号を反転して北の行からの順に並べ替える
+    for cell in &mut cells {
+        cell.0 = rows - 1 - cell.0;
+    }
+    cells.sort_unstable_by_key(|&(row, col, _)| (row, col));
+    cells
+}
+
+/// 境界ボックス全体を1つで含む最も細かいメッシュを返す
+///
+/// 細かいレベルから順に、南西端と北東端が同じメッシュに変換されるかを調べ、
+/// 最初に一致したメッシュを返します。境界ボックスの簡易的な空間キーとして使えます。
+/// 複数の1次メッシュにまたがる場合や、日本の範囲外で変換できない場合は`None`を
+/// 返します。北端・東端がちょうどメッシュ境界上にある場合は、隣のメッシュに
+/// かかるものとして扱います。
+///
+/// # 引数
+/// * `bbox` - 対象の境界ボックス
+///
+/// # 戻り値
+/// 境界ボックスを含むメッシュコード、または該当しない場合は`None`
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let bbox = BoundingBox::new(
+///     Coordinate::new(35.601, 139.701).unwrap(),
+///     Coordinate::new(35.649, 139.749).unwrap(),
+/// );
+/// let mesh = smallest_containing_mesh(&bbox).unwrap();
+/// assert_eq!(mesh.as_string(), "533935");
+/// ```
+pub fn smallest_containing_mesh(bbox: &BoundingBox) -> Option<MeshCode> {
+    const FINEST_FIRST: [MeshLevel; 7] = [
+        MeshLevel::Fifth,
+        MeshLevel::FourthEighth,
+        MeshLevel::FourthQuarter,
+        MeshLevel::FourthHalf,
+        MeshLevel::Third,
+        MeshLevel::Second,
+        MeshLevel::First,
+    ];
+
+    let mut cache = MeshPrefixCache::new();
+    FINEST_FIRST.iter().find_map(|&level| {
+        let sw = cache.convert(bbox.south_west(), level).ok()?;
+        let ne = cache.convert(bbox.north_east(), level).ok()?;
+        (sw == ne).then_some(sw)
+    })
+}
+
+/// 境界ボックス内のメッシュのうち外周にあるものだけを取得する
+///
+/// [`mesh_codes_in_bbox`]で得られるメッシュを格子状に並べたとき、最初・最後の行と
+/// 最初・最後の列にあるメッシュを返します。範囲の輪郭を描画する用途に使います。
+/// 順序は[`mesh_codes_in_bbox`]と同じです。
+///
+/// # 引数
+/// * `bbox` - 検索範囲を表す境界ボックス
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// 外周のメッシュコードのベクター
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// // 3次メッシュ5×5個分の範囲
+/// let sw = mesh_to_center(MeshCode::from_str("53394611").unwrap());
+/// let ne = mesh_to_center(MeshCode::from_str("53394655").unwrap());
+/// let bbox = BoundingBox::new(sw, ne);
+///
+/// assert_eq!(mesh_codes_in_bbox(bbox, MeshLevel::Third).count(), 25);
+/// assert_eq!(boundary_meshes_of_bbox(bbox, MeshLevel::Third).len(), 16);
+/// ```
+pub fn boundary_meshes_of_bbox(bbox: BoundingBox, level: MeshLevel) -> Vec<MeshCode> {
+    let meshes: Vec<(MeshCode, (i64, i64))> = MeshCodeIterator::new(bbox, level)
+        .map(|mesh| (mesh, mesh.grid_index()))
+        .collect();
+
+    let Some(lat_min) = meshes.iter().map(|&(_, (lat, _))| lat).min() else {
+        return Vec::new();
+    };
+    let lat_max = meshes
+        .iter()
+        .map(|&(_, (lat, _))| lat)
+        .max()
+        .unwrap_or(lat_min);
+    let lon_min = meshes.iter().map(|&(_, (_, lon))| lon).min().unwrap_or(0);
+    let lon_max = meshes.iter().map(|&(_, (_, lon))| lon).max().unwrap_or(0);
+
+    meshes
+        .into_iter()
+        .filter(|&(_, (lat, lon))| {
+            lat == lat_min || lat == lat_max || lon == lon_min || lon == lon_max
+        })
+        .map(|(mesh, _)| mesh)
+        .collect()
+}
+
+/// 複数の境界ボックス内のメッシュコードを重複なしで取得する
+///
+/// 隣接・重複する境界ボックスを個別に検索すると、境界上のメッシュが
+/// 重複して得られます。この関数は各境界ボックスの結果を統合し、
+/// 重複を除いてメッシュコードの昇順に並べたベクターを返します。
+///
+/// # 引数
+/// * `bboxes` - 検索範囲を表す境界ボックスのスライス
+/// * `level` - 目的のメッシュレベル
+///
+/// # 戻り値
+/// 重複を除きソートされたメッシュコードのベクター
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let a = BoundingBox::new(
+///     Coordinate::new(35.60, 139.70).unwrap(),
+///     Coordinate::new(35.65, 139.75).unwrap(),
+/// );
+/// let b = BoundingBox::new(
+///     Coordinate::new(35.64, 139.74).unwrap(),
+///     Coordinate::new(35.70, 139.80).unwrap(),
+/// );
+/// let meshes = mesh_codes_in_bboxes(&[a, b], MeshLevel::Third);
+/// assert!(meshes.windows(2).all(|w| w[0] < w[1]));
+/// ```
+pub fn mesh_codes_in_bboxes(bboxes: &[BoundingBox], level: MeshLevel) -> Vec<MeshCode> {
+    let set: BTreeSet<MeshCode> = bboxes
+        .iter()
+        .flat_map(|&bbox| mesh_codes_in_bbox(bbox, level))
+        .collect();
+    set.into_iter().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::Coordinate;
+    use crate::convert::mesh_to_center;
+
+    #[test]
+    fn test_bbox_mesh_grid_row_major_from_north() {
+        let sw = Coordinate::new(35.6, 139.7).unwrap();
+        let ne = Coordinate::new(35.7, 139.8).unwrap();
+        let bbox = BoundingBox::new(sw, ne);
+
+        let (rows, cols) = bbox_grid_dimensions(&bbox, MeshLevel::Third);
+        let cells = bbox_mesh_grid(bbox, MeshLevel::Third);
+        assert_eq!(cells.len(), rows * cols);
+        assert_eq!(cells.iter().map(|c| c.0).max(), Some(rows - 1));
+        assert_eq!(cells.iter().map(|c| c.1).max(), Some(cols - 1));
+
+        // 行優先で、行0が北端
+        for (i, &(row, col, _)) in cells.iter().enumerate() {
+            assert_eq!((row, col), (i / cols, i % cols));
+        }
+        let grid = mesh_codes_in_bbox_grid(bbox, MeshLevel::Third);
+        for &(row, col, mesh) in &cells {
+            assert_eq!(grid[rows - 1 - row][col], mesh);
+        }
+        let north = mesh_to_center(cells[0].2).lat();
+        let south = mesh_to_center(cells[cells.len() - 1].2).lat();
+        assert!(north > south);
+    }
+
+    #[test]
+    fn test_smallest_containing_mesh() {
+        let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+        let tiny = BoundingBox::new(
+            tokyo,
+            Coordinate::new(tokyo.lat() + 1e-5, tokyo.lon() + 1e-5).unwrap(),
+        );
+        let mesh = smallest_containing_mesh(&tiny).unwrap();
+        assert!(mesh.level() >= MeshLevel::Third);
+        let bounds = crate::convert::mesh_to_bounds(mesh);
+        assert!(bounds.contains(tiny.south_west()) && bounds.contains(tiny.north_east()));
+
+        // 2次メッシュをまたぎ、1次メッシュ5339に収まる範囲
+        let large = BoundingBox::new(
+            Coordinate::new(35.4, 139.1).unwrap(),
+            Coordinate::new(35.9, 139.9).unwrap(),
+        );
+        assert_eq!(
+            smallest_containing_mesh(&large).unwrap().as_string(),
+            "5339"
+        );
+
+        // 1次メッシュをまたぐ範囲
+        let huge = BoundingBox::new(
+            Coordinate::new(35.0, 139.0).unwrap(),
+            Coordinate::new(36.5, 140.5).unwrap(),
+        );
+        assert_eq!(smallest_containing_mesh(&huge), None);
+    }
+
+    #[test]
+    fn test_bbox_grid_dimensions_matches_iterator() {
+        let sw = Coordinate::new(35.6, 139.7).unwrap();
+        let ne = Coordinate::new(35.7, 139.8).unwrap();
+        let bbox = BoundingBox::new(sw, ne);
+
+        for level in [MeshLevel::Second, MeshLevel::Third, MeshLevel::FourthHalf] {
+            let (rows, cols) = bbox_grid_dimensions(&bbox, level);
+            let flat: Vec<MeshCode> = mesh_codes_in_bbox(bbox, level).collect();
+            assert_eq!(rows * cols, flat.len());
+
+            let grid = mesh_codes_in_bbox_grid(bbox, level);
+            assert_eq!(grid.len(), rows);
+            assert!(grid.iter().all(|row| row.len() == cols));
+            assert_eq!(grid.concat(), flat);
+        }
+
+        // 3次メッシュは南北30秒・東西45秒なので、0.1度四方は13行×9列
+        assert_eq!(bbox_grid_dimensions(&bbox, MeshLevel::Third), (13, 9));
+    }
+
+    #[test]
+    fn test_boundary_meshes_of_bbox() {
+        let sw = mesh_to_center(MeshCode::from_str("53394611").unwrap());
+        let ne = mesh_to_center(MeshCode::from_str("53394655").unwrap());
+        let bbox = BoundingBox::new(sw, ne);
+
+        let all: Vec<MeshCode> = mesh_codes_in_bbox(bbox, MeshLevel::Third).collect();
+        let boundary = boundary_meshes_of_bbox(bbox, MeshLevel::Third);
+        assert_eq!(all.len(), 25);
+        assert_eq!(boundary.len(), 16);
+
+        // 内側の3×3は含まれない
+        for code in ["53394622", "53394633", "53394644", "53394624"] {
+            let inner = MeshCode::from_str(code).unwrap();
+            assert!(all.contains(&inner));
+            assert!(!boundary.contains(&inner));
+        }
+        for code in ["53394611", "53394615", "53394651", "53394655", "53394613"] {
+            assert!(boundary.contains(&MeshCode::from_str(code).unwrap()));
+        }
+
+        // 1行・1列の範囲はすべてが外周
+        let row = BoundingBox::new(sw, mesh_to_center(MeshCode::from_str("53394615").unwrap()));
+        assert_eq!(boundary_meshes_of_bbox(row, MeshLevel::Third).len(), 5);
+    }
+
+    #[test]
+    fn test_with_centers_matches_mesh_to_center() {
+        let c = |lat, lon| Coordinate::new(lat, lon).unwrap();
+        // 東京付近と、北緯36度・東経140度の1次メッシュ境界をまたぐ範囲
+        let bboxes = [
+            BoundingBox::new(c(35.6, 139.7), c(35.7, 139.8)),
+            BoundingBox::new(c(35.99, 139.99), c(36.01, 140.01)),
+        ];
+
+        for bbox in bboxes {
+            for level in [
+                MeshLevel::First,
+                MeshLevel::Second,
+                MeshLevel::Third,
+                MeshLevel::FourthHalf,
+                MeshLevel::FourthQuarter,
+                MeshLevel::FourthEighth,
+                MeshLevel::Fifth,
+            ] {
+                let pairs: Vec<(MeshCode, Coordinate)> =
+                    mesh_codes_in_bbox_with_centers(bbox, level).collect();
+                let meshes: Vec<MeshCode> = mesh_codes_in_bbox(bbox, level).collect();
+
+                assert!(!pairs.is_empty());
+                assert_eq!(pairs.iter().map(|&(m, _)| m).collect::<Vec<_>>(), meshes);
+                for (mesh, center) in pairs {
+                    assert_eq!(center, mesh_to_center(mesh), "{mesh}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_iterator_keeps_returning_none() {
+        let sw = Coordinate::new(35.6, 139.7).unwrap();
+        let ne = Coordinate::new(35.65, 139.75).unwrap();
+        let mut iter = mesh_codes_in_bbox(BoundingBox::new(sw, ne), MeshLevel::Third);
+        assert!(iter.by_ref().count() > 0);
+        for _ in 0..5 {
+            assert_eq!(iter.next(), None);
+        }
+    }
 
     #[test]
     fn test_mesh_codes_in_bbox() {
@@ -93,4 +604,73 @@ mod tests {
         assert!(!meshes.is_empty());
         assert!(meshes.iter().all(|m| m.level() == MeshLevel::Third));
     }
+
+    #[test]
+    fn test_mesh_codes_in_bboxes_dedup() {
+        let a = BoundingBox::new(
+            Coordinate::new(35.60, 139.70).unwrap(),
+            Coordinate::new(35.65, 139.75).unwrap(),
+        );
+        let b = BoundingBox::new(
+            Coordinate::new(35.64, 139.74).unwrap(),
+            Coordinate::new(35.70, 139.80).unwrap(),
+        );
+
+        let merged = mesh_codes_in_bboxes(&[a, b], MeshLevel::Third);
+
+        // 重複がなく昇順
+        assert!(merged.windows(2).all(|w| w[0] < w[1]));
+
+        // 個別の検索結果の和集合と一致する
+        let mut expected: Vec<_> = mesh_codes_in_bbox(a, MeshLevel::Third)
+            .chain(mesh_codes_in_bbox(b, MeshLevel::Third))
+            .collect();
+        expected.sort();
+        expected.dedup();
+        assert_eq!(merged, expected);
+    }
+
+    #[test]
+    fn test_iterator_across_first_mesh_boundary() {
+        // 北緯36度・東経140度の1次メッシュ境界をまたぐ範囲
+        let sw = Coordinate::new(35.995, 139.985).unwrap();
+        let ne = Coordinate::new(36.005, 140.015).unwrap();
+        let bbox = BoundingBox::new(sw, ne);
+
+        let codes: Vec<_> = mesh_codes_in_bbox(bbox, MeshLevel::Third)
+            .map(|m| m.as_string())
+            .collect();
+        assert_eq!(
+            codes,
+            ["53397798", "53397799", "53407090", "54390708", "54390709", "54400000"]
+        );
+    }
+
+    #[test]
+    fn test_iterator_matches_per_cell_conversion() {
+        let sw = Coordinate::new(35.58, 139.88).unwrap();
+        let ne = Coordinate::new(35.78, 140.08).unwrap();
+        let bbox = BoundingBox::new(sw, ne);
+        let level = MeshLevel::Third;
+
+        let optimized: Vec<_> = mesh_codes_in_bbox(bbox, level).collect();
+
+        // 各グリッドセルで毎回coord_to_meshを呼ぶ従来の走査
+        let mut expected = Vec::new();
+        let mut lat = bbox.min_lat();
+        while lat <= bbox.max_lat() {
+            let mut lon = bbox.min_lon();
+            while lon <= bbox.max_lon() {
+                let coord = Coordinate::new_unchecked(lat, lon);
+                if let Ok(mesh) = crate::convert::coord_to_mesh(coord, level) {
+                    expected.push(mesh);
+                }
+                lon += level.lon_size_degrees();
+            }
+            lat += level.lat_size_degrees();
+        }
+
+        assert!(!optimized.is_empty());
+        assert_eq!(optimized, expected);
+    }
 }
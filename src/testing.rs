@@ -0,0 +1,138 @@
+//! 下流クレートでも使えるプロパティテスト用の補助関数
+//!
+//! `testing`フィーチャー有効時に公開されます。このクレートを拡張する側でも、
+//! 座標とメッシュコードの変換が満たすべき性質を同じ基準で検証できます。
+//!
+//! 乱数生成器には依存せず、`[0, 1)`の一様乱数を返すクロージャを受け取ります。
+
+use crate::convert::{coord_to_mesh, mesh_to_bounds, mesh_to_center};
+use crate::types::{Coordinate, MeshCode, MeshLevel, JAPAN_BOUNDS};
+
+/// 座標→メッシュ→境界・中心・文字列のラウンドトリップが成立することを検証する
+///
+/// 次の性質を確認し、満たさない場合はパニックします。
+///
+/// - 座標を変換したメッシュが指定レベルで、その境界が座標を含む
+/// - メッシュの中心座標（日本の範囲内の場合）を変換すると同じメッシュに戻る
+/// - メッシュコード文字列をレベル指定でパースすると同じメッシュに戻る
+///
+/// # 引数
+/// * `coord` - 日本の範囲内の座標
+/// * `level` - 変換するメッシュレベル
+///
+/// # 戻り値
+/// 座標を変換したメッシュコード
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+/// use jismeshcode::testing::assert_roundtrip;
+///
+/// let coord = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let mesh = assert_roundtrip(coord, MeshLevel::Third);
+/// assert_eq!(mesh.as_string(), "53394611");
+/// ```
+pub fn assert_roundtrip(coord: Coordinate, level: MeshLevel) -> MeshCode {
+    let (lat, lon) = (coord.lat(), coord.lon());
+    let mesh = coord_to_mesh(coord, level)
+        .unwrap_or_else(|e| panic!("coord_to_mesh failed at ({lat}, {lon}): {e}"));
+    assert_eq!(mesh.level(), level, "level mismatch at ({lat}, {lon})");
+
+    let bounds = mesh_to_bounds(mesh);
+    assert!(
+        bounds.contains(coord),
+        "{level:?} mesh {mesh} does not contain ({lat}, {lon})"
+    );
+
+    let center = mesh_to_center(mesh);
+    if let Ok(center) = Coordinate::new(center.lat(), center.lon()) {
+        assert_eq!(
+            coord_to_mesh(center, level).ok(),
+            Some(mesh),
+            "center of {mesh} does not map back to itself"
+        );
+    }
+
+    assert_eq!(
+        MeshCode::parse(&mesh.as_string(), Some(level)).ok(),
+        Some(mesh),
+        "{mesh} does not parse back as {level:?}"
+    );
+
+    mesh
+}
+
+/// 日本の範囲（北緯20〜46度、東経122〜154度）内の座標を一様に生成する
+///
+/// # 引数
+/// * `rng` - `[0, 1)`の一様乱数を返すクロージャ
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::testing::random_coordinate_in_japan;
+///
+/// // 簡易な線形合同法
+/// let mut state: u64 = 42;
+/// let mut rng = || {
+///     state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+///     (state >> 11) as f64 / (1u64 << 53) as f64
+/// };
+/// let coord = random_coordinate_in_japan(&mut rng);
+/// assert!((20.0..46.0).contains(&coord.lat()));
+/// ```
+pub fn random_coordinate_in_japan<R: FnMut() -> f64>(rng: &mut R) -> Coordinate {
+    let (min_lat, max_lat) = (JAPAN_BOUNDS.min_lat(), JAPAN_BOUNDS.max_lat());
+    let (min_lon, max_lon) = (JAPAN_BOUNDS.min_lon(), JAPAN_BOUNDS.max_lon());
+    let lat = min_lat + rng() * (max_lat - min_lat);
+    let lon = min_lon + rng() * (max_lon - min_lon);
+    Coordinate::new_unchecked(lat.clamp(min_lat, max_lat), lon.clamp(min_lon, max_lon))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_LEVELS: [MeshLevel; 7] = [
+        MeshLevel::First,
+        MeshLevel::Second,
+        MeshLevel::Third,
+        MeshLevel::FourthHalf,
+        MeshLevel::FourthQuarter,
+        MeshLevel::FourthEighth,
+        MeshLevel::Fifth,
+    ];
+
+    #[test]
+    fn test_roundtrip_over_grid() {
+        for i in 0..=26 {
+            for j in 0..=32 {
+                let coord =
+                    Coordinate::new(20.0 + i as f64 * 0.99, 122.0 + j as f64 * 0.99).unwrap();
+                for level in ALL_LEVELS {
+                    assert_roundtrip(coord, level);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_random() {
+        let mut state: u64 = 0x5339_4611;
+        let mut rng = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+
+        for _ in 0..500 {
+            let coord = random_coordinate_in_japan(&mut rng);
+            assert!(Coordinate::new(coord.lat(), coord.lon()).is_ok());
+            for level in ALL_LEVELS {
+                assert_roundtrip(coord, level);
+            }
+        }
+    }
+}
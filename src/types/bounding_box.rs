@@ -1,4 +1,36 @@
 use crate::types::coordinate::Coordinate;
+use crate::types::mesh_level::MeshLevel;
+use crate::utils::distance::{calculate_bbox_offsets, EARTH_RADIUS_METERS};
+use crate::utils::math;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// メッシュ計算で扱う日本の範囲（緯度20〜46度、経度122〜154度）
+///
+/// 座標の検証や隣接メッシュの範囲判定はすべてこの値を参照します。
+pub(crate) const JAPAN_BOUNDS: BoundingBox = BoundingBox::new(
+    Coordinate::new_unchecked(20.0, 122.0),
+    Coordinate::new_unchecked(46.0, 154.0),
+);
+
+/// メッシュ計算で扱う日本の範囲を返す
+///
+/// [`Coordinate::new`]が受け付ける範囲（緯度20〜46度、経度122〜154度、境界を含む）と
+/// 同じです。沖ノ鳥島（北緯約20.4度）や南鳥島（東経約153.99度）も含まれます。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let japan = japan_mesh_bounds();
+/// assert_eq!(japan.min_lat(), 20.0);
+/// assert_eq!(japan.max_lon(), 154.0);
+/// assert!(japan.contains(Coordinate::new(35.6812, 139.7671).unwrap()));
+/// ```
+pub const fn japan_mesh_bounds() -> BoundingBox {
+    JAPAN_BOUNDS
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -8,13 +40,48 @@ pub struct BoundingBox {
 }
 
 impl BoundingBox {
-    pub fn new(south_west: Coordinate, north_east: Coordinate) -> Self {
+    pub const fn new(south_west: Coordinate, north_east: Coordinate) -> Self {
         BoundingBox {
             south_west,
             north_east,
         }
     }
 
+    /// 中心座標と東西・南北方向の半分の長さ（メートル）から境界ボックスを作成する
+    ///
+    /// 半径検索と同じ緯度補正付きのオフセット計算で度単位の幅を求め、
+    /// 日本の範囲（北緯20〜46度、東経122〜154度）にクランプします。
+    ///
+    /// # 引数
+    /// * `center` - 中心座標
+    /// * `half_width_m` - 中心から東端・西端までの距離（メートル）
+    /// * `half_height_m` - 中心から北端・南端までの距離（メートル）
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// // 東京駅を中心とする2km四方の境界ボックス
+    /// let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+    /// let bbox = BoundingBox::from_center_meters(tokyo, 1000.0, 1000.0);
+    /// assert!(bbox.contains(tokyo));
+    /// ```
+    pub fn from_center_meters(center: Coordinate, half_width_m: f64, half_height_m: f64) -> Self {
+        let (lat_offset, _) = calculate_bbox_offsets(center, half_height_m);
+        let (_, lon_offset) = calculate_bbox_offsets(center, half_width_m);
+
+        let min_lat = (center.lat() - lat_offset).max(JAPAN_BOUNDS.min_lat());
+        let max_lat = (center.lat() + lat_offset).min(JAPAN_BOUNDS.max_lat());
+        let min_lon = (center.lon() - lon_offset).max(JAPAN_BOUNDS.min_lon());
+        let max_lon = (center.lon() + lon_offset).min(JAPAN_BOUNDS.max_lon());
+
+        BoundingBox::new(
+            Coordinate::new_unchecked(min_lat, min_lon),
+            Coordinate::new_unchecked(max_lat, max_lon),
+        )
+    }
+
     pub fn south_west(&self) -> Coordinate {
         self.south_west
     }
@@ -51,12 +118,236 @@ impl BoundingBox {
         let lon = (self.min_lon() + self.max_lon()) / 2.0;
         Coordinate::new_unchecked(lat, lon)
     }
+
+    /// 境界ボックスのおおよその面積を平方メートルで返す
+    ///
+    /// 南北方向の長さに、中央の緯度で補正した東西方向の長さを掛けて求めます。
+    /// 同じ度数の範囲でも高緯度ほど面積は小さくなります。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let bbox = BoundingBox::new(
+    ///     Coordinate::new(35.0, 139.0).unwrap(),
+    ///     Coordinate::new(36.0, 140.0).unwrap(),
+    /// );
+    /// let area = bbox.area_square_meters();
+    /// assert!((area - 1.0065e10).abs() < 1e8);
+    /// ```
+    pub fn area_square_meters(&self) -> f64 {
+        let mean_lat = (self.min_lat() + self.max_lat()) / 2.0;
+        let height = (self.max_lat() - self.min_lat()).to_radians() * EARTH_RADIUS_METERS;
+        let width = (self.max_lon() - self.min_lon()).to_radians()
+            * EARTH_RADIUS_METERS
+            * math::cos(mean_lat.to_radians());
+        width * height
+    }
+
+    /// 境界ボックスを指定レベルのメッシュ境界に揃えたタイルに分割する
+    ///
+    /// 境界ボックスと重なる指定レベルのメッシュ1つ1つを、そのメッシュの範囲を持つ
+    /// タイルとして返します。タイル同士は辺を共有するだけで重ならず、
+    /// すべてのタイルの和集合は元の境界ボックスを覆います。境界ボックスの辺が
+    /// ちょうどメッシュ境界上にある場合、外側で接するだけのメッシュのタイルは含みません。
+    /// 並列処理で各タイルをワーカーに割り当て、より細かいレベルで
+    /// [`crate::mesh_codes_in_bbox`]を実行する用途を想定しています。
+    ///
+    /// タイルは南の行から北の行へ、各行は西から東への順に並びます。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let bbox = BoundingBox::new(
+    ///     Coordinate::new(35.5, 139.5).unwrap(),
+    ///     Coordinate::new(35.8, 139.9).unwrap(),
+    /// );
+    /// let tiles = bbox.tile_by_level(MeshLevel::Second);
+    /// assert!(!tiles.is_empty());
+    /// ```
+    pub fn tile_by_level(&self, level: MeshLevel) -> Vec<BoundingBox> {
+        let lat_size = level.lat_size_degrees();
+        let lon_size = level.lon_size_degrees();
+
+        let (lat_start, lat_end) = grid_index_range(self.min_lat(), self.max_lat(), lat_size);
+        let (lon_start, lon_end) = grid_index_range(self.min_lon(), self.max_lon(), lon_size);
+
+        let mut tiles =
+            Vec::with_capacity(((lat_end - lat_start) * (lon_end - lon_start)) as usize);
+        for i in lat_start..lat_end {
+            for j in lon_start..lon_end {
+                let sw = Coordinate::new_unchecked(i as f64 * lat_size, j as f64 * lon_size);
+                let ne =
+                    Coordinate::new_unchecked((i + 1) as f64 * lat_size, (j + 1) as f64 * lon_size);
+                tiles.push(BoundingBox::new(sw, ne));
+            }
+        }
+        tiles
+    }
+
+    /// 境界ボックスを指定レベルのメッシュ境界まで外側に広げる
+    ///
+    /// 南西端を下側（南・西）の、北東端を上側（北・東）のメッシュ境界線に揃え、
+    /// 元の境界ボックスを完全に含む境界ボックスを返します。結果の各辺は
+    /// [`crate::mesh_to_bounds`]が返すメッシュの辺と一致し、
+    /// [`BoundingBox::tile_by_level`]が返すタイルの和集合と等しくなります。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let bbox = BoundingBox::new(
+    ///     Coordinate::new(35.601, 139.701).unwrap(),
+    ///     Coordinate::new(35.649, 139.749).unwrap(),
+    /// );
+    /// let snapped = bbox.snap_to_mesh_grid(MeshLevel::Third);
+    /// assert!(snapped.contains(bbox.south_west()));
+    /// assert!(snapped.contains(bbox.north_east()));
+    /// ```
+    pub fn snap_to_mesh_grid(&self, level: MeshLevel) -> BoundingBox {
+        let lat_size = level.lat_size_degrees();
+        let lon_size = level.lon_size_degrees();
+
+        let (lat_start, lat_end) = grid_index_range(self.min_lat(), self.max_lat(), lat_size);
+        let (lon_start, lon_end) = grid_index_range(self.min_lon(), self.max_lon(), lon_size);
+
+        BoundingBox::new(
+            Coordinate::new_unchecked(lat_start as f64 * lat_size, lon_start as f64 * lon_size),
+            Coordinate::new_unchecked(lat_end as f64 * lat_size, lon_end as f64 * lon_size),
+        )
+    }
+
+    /// 境界ボックスと接する指定レベルのメッシュの格子番号の範囲を返す
+    ///
+    /// 格子番号は[`MeshCode`](crate::types::MeshCode)の内部表現と同じく、
+    /// 緯度は北緯0度から、経度は東経100度から数えます。終端は範囲に含みません。
+    /// 辺がちょうどメッシュ境界上にある場合は、外側で接するメッシュも含みます
+    /// （境界上の座標は浮動小数点誤差でどちらのメッシュにも変換され得るため）。
+    pub(crate) fn grid_index_bounds(&self, level: MeshLevel) -> (Range<i64>, Range<i64>) {
+        let lat_size = level.lat_size_degrees();
+        let lon_size = level.lon_size_degrees();
+        let lat_start = math::floor(self.min_lat() / lat_size - GRID_TOUCH_EPSILON) as i64;
+        let lon_start =
+            math::floor((self.min_lon() - 100.0) / lon_size - GRID_TOUCH_EPSILON) as i64;
+        let lat_end = math::floor(self.max_lat() / lat_size + GRID_TOUCH_EPSILON) as i64 + 1;
+        let lon_end =
+            math::floor((self.max_lon() - 100.0) / lon_size + GRID_TOUCH_EPSILON) as i64 + 1;
+        (lat_start..lat_end, lon_start..lon_end)
+    }
 }
 
+/// 区間`[min, max]`と重なるグリッドセルの番号範囲（終端は含まない）を返す
+///
+/// `min`・`max`がグリッド線上（浮動小数点誤差を含む）にある場合、外側で接するだけの
+/// セルは含めません。幅0の区間でも少なくとも1セルを返します。
+fn grid_index_range(min: f64, max: f64, size: f64) -> (i64, i64) {
+    let start = math::floor(min / size + GRID_EPSILON) as i64;
+    let end = (math::ceil(max / size - GRID_EPSILON) as i64).max(start + 1);
+    (start, end)
+}
+
+/// グリッド線上とみなす誤差（セル数単位）
+const GRID_EPSILON: f64 = 1e-9;
+
+/// [`BoundingBox::grid_index_bounds`]で外側に接するセルを含めるための誤差（セル数単位）
+///
+/// 座標からメッシュコードへの変換は緯度を1.5倍するなど格子番号の計算とは別の経路で
+/// 丸められるため、境界上の座標が隣のセルに変換されることがあります。取りこぼすと
+/// ビットセットから変換結果が漏れるので、[`GRID_EPSILON`]より緩い許容誤差で範囲を広げます。
+const GRID_TOUCH_EPSILON: f64 = 1e-6;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_japan_mesh_bounds_matches_coordinate_validation() {
+        let japan = japan_mesh_bounds();
+        let (min_lat, max_lat) = (japan.min_lat(), japan.max_lat());
+        let (min_lon, max_lon) = (japan.min_lon(), japan.max_lon());
+
+        for (lat, lon) in [
+            (min_lat, min_lon),
+            (min_lat, max_lon),
+            (max_lat, min_lon),
+            (max_lat, max_lon),
+        ] {
+            assert!(Coordinate::new(lat, lon).is_ok());
+        }
+
+        let eps = 1e-9;
+        for (lat, lon) in [
+            (min_lat - eps, 139.0),
+            (max_lat + eps, 139.0),
+            (35.0, min_lon - eps),
+            (35.0, max_lon + eps),
+        ] {
+            assert!(Coordinate::new(lat, lon).is_err());
+        }
+
+        for i in 0..=30 {
+            for j in 0..=40 {
+                let lat = 18.0 + i as f64 * 1.0;
+                let lon = 120.0 + j as f64 * 1.0;
+                let inside = japan.contains(Coordinate::new_unchecked(lat, lon));
+                assert_eq!(Coordinate::new(lat, lon).is_ok(), inside);
+            }
+        }
+    }
+
+    #[test]
+    fn test_area_square_meters() {
+        let bbox_at = |lat: f64| {
+            BoundingBox::new(
+                Coordinate::new(lat, 139.0).unwrap(),
+                Coordinate::new(lat + 1.0, 140.0).unwrap(),
+            )
+        };
+
+        // 1度 × 1度: 南北約111.2km、東西約111.2km × cos(35.5度) ≒ 90.5km
+        let tokyo = bbox_at(35.0).area_square_meters();
+        assert!((tokyo - 1.0065e10).abs() / 1.0065e10 < 0.01, "{tokyo}");
+
+        // 高緯度ほど小さくなる
+        assert!(bbox_at(44.0).area_square_meters() < tokyo);
+        assert!(bbox_at(25.0).area_square_meters() > tokyo);
+
+        // メッシュの面積と一致する
+        let mesh = crate::types::MeshCode::from_str("53394611").unwrap();
+        let bounds = crate::convert::mesh_to_bounds(mesh);
+        let diff = bounds.area_square_meters() - mesh.area_square_meters();
+        assert!(diff.abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_from_center_meters() {
+        use crate::utils::distance::haversine_distance;
+
+        let center = Coordinate::new(35.6812, 139.7671).unwrap();
+        let bbox = BoundingBox::from_center_meters(center, 2000.0, 1000.0);
+        let expected = (2000.0f64 * 2000.0 + 1000.0 * 1000.0).sqrt();
+
+        for corner in [bbox.south_west(), bbox.north_east()] {
+            let distance = haversine_distance(center, corner);
+            assert!((distance - expected).abs() / expected < 0.01, "{distance}");
+        }
+        assert!((bbox.center().lat() - center.lat()).abs() < 1e-9);
+        assert!((bbox.center().lon() - center.lon()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_center_meters_clamped() {
+        let center = Coordinate::new(45.9, 153.9).unwrap();
+        let bbox = BoundingBox::from_center_meters(center, 50000.0, 50000.0);
+        assert_eq!(bbox.max_lat(), 46.0);
+        assert_eq!(bbox.max_lon(), 154.0);
+        assert!(bbox.min_lat() < 45.9);
+    }
+
     #[test]
     fn test_bounding_box() {
         let sw = Coordinate::new_unchecked(35.0, 139.0);
@@ -92,4 +383,140 @@ mod tests {
         assert_eq!(center.lat(), 35.5);
         assert_eq!(center.lon(), 139.5);
     }
+
+    #[test]
+    fn test_tile_by_level_covers_without_overlap() {
+        let sw = Coordinate::new_unchecked(35.55, 139.55);
+        let ne = Coordinate::new_unchecked(35.80, 139.90);
+        let bbox = BoundingBox::new(sw, ne);
+
+        let tiles = bbox.tile_by_level(MeshLevel::Second);
+        assert!(tiles.len() > 1);
+
+        // 元の境界ボックス内の点はいずれかのタイルに含まれる
+        for i in 0..=10 {
+            for j in 0..=10 {
+                let lat = 35.55 + 0.025 * i as f64;
+                let lon = 139.55 + 0.035 * j as f64;
+                let point = Coordinate::new_unchecked(lat, lon);
+                assert!(tiles.iter().any(|t| t.contains(point)));
+            }
+        }
+
+        // タイル同士は重ならない（辺の共有のみ）
+        for (i, a) in tiles.iter().enumerate() {
+            for b in &tiles[i + 1..] {
+                let lat_overlap = a.max_lat().min(b.max_lat()) - a.min_lat().max(b.min_lat());
+                let lon_overlap = a.max_lon().min(b.max_lon()) - a.min_lon().max(b.min_lon());
+                assert!(lat_overlap <= 1e-9 || lon_overlap <= 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tile_by_level_aligned_to_mesh() {
+        let sw = Coordinate::new_unchecked(35.55, 139.55);
+        let ne = Coordinate::new_unchecked(35.80, 139.90);
+        let bbox = BoundingBox::new(sw, ne);
+
+        for tile in bbox.tile_by_level(MeshLevel::Second) {
+            let mesh = crate::convert::coord_to_mesh(tile.center(), MeshLevel::Second).unwrap();
+            let bounds = crate::convert::mesh_to_bounds(mesh);
+            assert!((tile.min_lat() - bounds.min_lat()).abs() < 1e-9);
+            assert!((tile.min_lon() - bounds.min_lon()).abs() < 1e-9);
+            assert!((tile.max_lat() - bounds.max_lat()).abs() < 1e-9);
+            assert!((tile.max_lon() - bounds.max_lon()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_tile_by_level_of_mesh_bounds_is_that_mesh() {
+        for code in [
+            "5339",
+            "533946",
+            "53394611",
+            "533946111",
+            "5339461111",
+            "64414277",
+            "36225777",
+        ] {
+            let mesh = crate::types::MeshCode::from_str(code).unwrap();
+            let bounds = crate::convert::mesh_to_bounds(mesh);
+
+            let tiles = bounds.tile_by_level(mesh.level());
+            assert_eq!(tiles.len(), 1, "{code}");
+            let tile_mesh = crate::convert::coord_to_mesh(tiles[0].center(), mesh.level());
+            assert_eq!(tile_mesh, Ok(mesh));
+        }
+    }
+
+    #[test]
+    fn test_snap_to_mesh_grid_of_mesh_bounds_is_unchanged() {
+        for code in [
+            "5339",
+            "533946",
+            "53394611",
+            "533946111",
+            "5339461111",
+            "53394611111",
+            "64414277",
+            "36225777",
+        ] {
+            let mesh = crate::types::MeshCode::from_str(code).unwrap();
+            let bounds = crate::convert::mesh_to_bounds(mesh);
+
+            let snapped = bounds.snap_to_mesh_grid(mesh.level());
+            assert!(
+                (snapped.min_lat() - bounds.min_lat()).abs() < 1e-9,
+                "{code}"
+            );
+            assert!(
+                (snapped.min_lon() - bounds.min_lon()).abs() < 1e-9,
+                "{code}"
+            );
+            assert!(
+                (snapped.max_lat() - bounds.max_lat()).abs() < 1e-9,
+                "{code}"
+            );
+            assert!(
+                (snapped.max_lon() - bounds.max_lon()).abs() < 1e-9,
+                "{code}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_snap_to_mesh_grid() {
+        let sw = Coordinate::new_unchecked(35.601, 139.701);
+        let ne = Coordinate::new_unchecked(35.649, 139.749);
+        let bbox = BoundingBox::new(sw, ne);
+        let level = MeshLevel::Third;
+
+        let snapped = bbox.snap_to_mesh_grid(level);
+        assert!(snapped.contains(sw) && snapped.contains(ne));
+
+        // 辺はメッシュの辺と一致する
+        let sw_mesh = crate::convert::coord_to_mesh(sw, level).unwrap();
+        let ne_mesh = crate::convert::coord_to_mesh(ne, level).unwrap();
+        let sw_bounds = crate::convert::mesh_to_bounds(sw_mesh);
+        let ne_bounds = crate::convert::mesh_to_bounds(ne_mesh);
+        assert!((snapped.min_lat() - sw_bounds.min_lat()).abs() < 1e-9);
+        assert!((snapped.min_lon() - sw_bounds.min_lon()).abs() < 1e-9);
+        assert!((snapped.max_lat() - ne_bounds.max_lat()).abs() < 1e-9);
+        assert!((snapped.max_lon() - ne_bounds.max_lon()).abs() < 1e-9);
+
+        // 元の範囲のメッシュはすべて広げた境界ボックス内に収まる
+        for mesh in crate::spatial::mesh_codes_in_bbox(bbox, level) {
+            let b = crate::convert::mesh_to_bounds(mesh);
+            assert!(b.min_lat() >= snapped.min_lat() - 1e-9);
+            assert!(b.min_lon() >= snapped.min_lon() - 1e-9);
+            assert!(b.max_lat() <= snapped.max_lat() + 1e-9);
+            assert!(b.max_lon() <= snapped.max_lon() + 1e-9);
+        }
+
+        // タイルの和集合と一致する
+        let tiles = bbox.tile_by_level(level);
+        assert_eq!(tiles.first().unwrap().south_west(), snapped.south_west());
+        assert_eq!(tiles.last().unwrap().north_east(), snapped.north_east());
+    }
 }
@@ -1,4 +1,6 @@
 use crate::error::{CoordResult, CoordinateError};
+use crate::types::bounding_box::JAPAN_BOUNDS;
+use crate::utils::math;
 
 /// 地理座標（緯度経度）を表す型
 ///
@@ -90,7 +92,7 @@ impl Coordinate {
     /// 範囲チェックなしで新しい座標を作成する
     ///
     /// 内部使用のため、範囲バリデーションをスキップします。
-    pub fn new_unchecked(lat: f64, lon: f64) -> Self {
+    pub const fn new_unchecked(lat: f64, lon: f64) -> Self {
         Coordinate { lat, lon }
     }
 
@@ -104,8 +106,118 @@ impl Coordinate {
         self.lon
     }
 
+    /// 緯度をラジアンで返す
+    pub fn lat_rad(&self) -> f64 {
+        self.lat.to_radians()
+    }
+
+    /// 経度をラジアンで返す
+    pub fn lon_rad(&self) -> f64 {
+        self.lon.to_radians()
+    }
+
+    /// 緯度・経度を指定した小数点以下の桁数に丸めた座標を返す
+    ///
+    /// GPSなどの末尾の桁の揺らぎでメッシュ境界の判定が変わらないよう、
+    /// 変換前に意味のある精度へ揃える用途に使います。丸めは四捨五入（0.5は0から
+    /// 遠い方へ）で、範囲チェックは行いません。桁数が15を超える場合は丸めを行わず
+    /// そのまま返します（`f64`の有効桁数を超えるため）。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let coord = Coordinate::new(35.68123456789, 139.76712345678).unwrap();
+    /// let rounded = coord.rounded(4);
+    /// assert_eq!(rounded.lat(), 35.6812);
+    /// assert_eq!(rounded.lon(), 139.7671);
+    /// ```
+    pub fn rounded(&self, decimals: u32) -> Coordinate {
+        if decimals > 15 {
+            return *self;
+        }
+
+        let factor = (0..decimals).fold(1.0, |factor, _| factor * 10.0);
+        Coordinate::new_unchecked(
+            math::round(self.lat * factor) / factor,
+            math::round(self.lon * factor) / factor,
+        )
+    }
+
+    /// 他の座標との大円上の中点を返す
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let a = Coordinate::new(35.0, 135.0).unwrap();
+    /// let b = Coordinate::new(36.0, 136.0).unwrap();
+    /// let mid = a.midpoint(b);
+    /// assert!((haversine_distance(a, mid) - haversine_distance(mid, b)).abs() < 1.0);
+    /// ```
+    pub fn midpoint(&self, other: Coordinate) -> Coordinate {
+        let lat1 = self.lat.to_radians();
+        let lon1 = self.lon.to_radians();
+        let lat2 = other.lat.to_radians();
+        let dlon = other.lon.to_radians() - lon1;
+
+        let bx = math::cos(lat2) * math::cos(dlon);
+        let by = math::cos(lat2) * math::sin(dlon);
+        let lat = math::atan2(
+            math::sin(lat1) + math::sin(lat2),
+            math::sqrt((math::cos(lat1) + bx) * (math::cos(lat1) + bx) + by * by),
+        );
+        let lon = lon1 + math::atan2(by, math::cos(lat1) + bx);
+
+        Coordinate::new_unchecked(lat.to_degrees(), lon.to_degrees())
+    }
+
+    /// 他の座標との大円上で、`t`（0.0〜1.0）の割合の位置にある座標を返す
+    ///
+    /// `t = 0.0`で自身、`t = 1.0`で`other`になります。経路上の点は日本の範囲を
+    /// わずかに超えることがあるため、範囲チェックは行いません。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let a = Coordinate::new(35.0, 135.0).unwrap();
+    /// let b = Coordinate::new(43.0, 141.0).unwrap();
+    /// let quarter = a.interpolate(b, 0.25);
+    /// let total = haversine_distance(a, b);
+    /// assert!((haversine_distance(a, quarter) - total * 0.25).abs() < 1.0);
+    /// ```
+    pub fn interpolate(&self, other: Coordinate, t: f64) -> Coordinate {
+        let lat1 = self.lat.to_radians();
+        let lon1 = self.lon.to_radians();
+        let lat2 = other.lat.to_radians();
+        let lon2 = other.lon.to_radians();
+
+        // 2点間の中心角
+        let sin_dlat = math::sin((lat2 - lat1) / 2.0);
+        let sin_dlon = math::sin((lon2 - lon1) / 2.0);
+        let h = sin_dlat * sin_dlat + math::cos(lat1) * math::cos(lat2) * sin_dlon * sin_dlon;
+        let delta = 2.0 * math::atan2(math::sqrt(h), math::sqrt(1.0 - h));
+        if delta == 0.0 {
+            return *self;
+        }
+
+        let a = math::sin((1.0 - t) * delta) / math::sin(delta);
+        let b = math::sin(t * delta) / math::sin(delta);
+        let x = a * math::cos(lat1) * math::cos(lon1) + b * math::cos(lat2) * math::cos(lon2);
+        let y = a * math::cos(lat1) * math::sin(lon1) + b * math::cos(lat2) * math::sin(lon2);
+        let z = a * math::sin(lat1) + b * math::sin(lat2);
+
+        let lat = math::atan2(z, math::sqrt(x * x + y * y));
+        let lon = math::atan2(y, x);
+        Coordinate::new_unchecked(lat.to_degrees(), lon.to_degrees())
+    }
+
     fn is_in_japan_range(lat: f64, lon: f64) -> bool {
-        (20.0..=46.0).contains(&lat) && (122.0..=154.0).contains(&lon)
+        JAPAN_BOUNDS.contains(Coordinate::new_unchecked(lat, lon))
     }
 }
 
@@ -113,6 +225,45 @@ impl Coordinate {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_rounded_removes_jitter() {
+        use crate::convert::coord_to_mesh;
+        use crate::types::MeshLevel;
+
+        // 3次メッシュの境界（北緯35.675度）をまたぐ揺らぎ
+        let a = Coordinate::new(35.674_999_999_8, 139.7671).unwrap();
+        let b = Coordinate::new(35.675_000_000_1, 139.7671).unwrap();
+        assert_ne!(
+            coord_to_mesh(a, MeshLevel::Third).unwrap(),
+            coord_to_mesh(b, MeshLevel::Third).unwrap()
+        );
+
+        let (ra, rb) = (a.rounded(6), b.rounded(6));
+        assert_eq!(ra, rb);
+        assert_eq!(
+            coord_to_mesh(ra, MeshLevel::Third).unwrap(),
+            coord_to_mesh(rb, MeshLevel::Third).unwrap()
+        );
+        assert_eq!(a.rounded(0), Coordinate::new_unchecked(36.0, 140.0));
+        assert_eq!(a.rounded(16), a);
+    }
+
+    #[test]
+    fn test_interpolate_half_equals_midpoint() {
+        let a = Coordinate::new(33.5902, 130.4017).unwrap();
+        let b = Coordinate::new(43.0687, 141.3508).unwrap();
+        let mid = a.midpoint(b);
+        let half = a.interpolate(b, 0.5);
+        assert!((mid.lat() - half.lat()).abs() < 1e-9);
+        assert!((mid.lon() - half.lon()).abs() < 1e-9);
+
+        let start = a.interpolate(b, 0.0);
+        let end = a.interpolate(b, 1.0);
+        assert!((start.lat() - a.lat()).abs() < 1e-9 && (start.lon() - a.lon()).abs() < 1e-9);
+        assert!((end.lat() - b.lat()).abs() < 1e-9 && (end.lon() - b.lon()).abs() < 1e-9);
+        assert_eq!(a.interpolate(a, 0.3), a);
+    }
+
     #[test]
     fn test_valid_coordinate() {
         let coord = Coordinate::new(35.6812, 139.7671).unwrap();
@@ -14,6 +14,9 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// すべての方向（北から時計回り）
+    ///
+    /// この順序は固定で、[`Direction::index`]はこの配列内の位置を返します。
     pub const ALL: [Direction; 8] = [
         Direction::North,
         Direction::NorthEast,
@@ -25,6 +28,42 @@ impl Direction {
         Direction::NorthWest,
     ];
 
+    /// [`Direction::ALL`]内での位置を返す
+    ///
+    /// 北=0から時計回りに北西=7までの値です。方向ごとの値を配列に格納する場合の
+    /// 添字として使えます（[`crate::neighbors_array`]と同じ並びです）。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// assert_eq!(Direction::North.index(), 0);
+    /// assert_eq!(Direction::East.index(), 2);
+    /// assert_eq!(Direction::ALL[Direction::SouthWest.index()], Direction::SouthWest);
+    /// ```
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// [`Direction::ALL`]内の位置から方向を返す（8以上の場合はNone）
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// assert_eq!(Direction::from_index(2), Some(Direction::East));
+    /// assert_eq!(Direction::from_index(8), None);
+    /// ```
+    pub const fn from_index(index: usize) -> Option<Direction> {
+        if index < Self::ALL.len() {
+            Some(Self::ALL[index])
+        } else {
+            None
+        }
+    }
+
     pub fn offset(self) -> (i32, i32) {
         match self {
             Direction::North => (0, 1),
@@ -79,6 +118,16 @@ mod tests {
         assert_eq!(Direction::SouthWest.offset(), (-1, -1));
     }
 
+    #[test]
+    fn test_index_roundtrip() {
+        for (i, &dir) in Direction::ALL.iter().enumerate() {
+            assert_eq!(dir.index(), i);
+            assert_eq!(Direction::from_index(dir.index()), Some(dir));
+        }
+        assert_eq!(Direction::from_index(8), None);
+        assert_eq!(Direction::from_index(usize::MAX), None);
+    }
+
     #[test]
     fn test_opposite() {
         assert_eq!(Direction::North.opposite(), Direction::South);
@@ -0,0 +1,167 @@
+use crate::error::{MeshCodeError, Result};
+use crate::types::mesh_code::MeshCode;
+use crate::types::mesh_level::MeshLevel;
+use alloc::format;
+
+/// 分割地域メッシュ（4次メッシュ）のレベルを型で区別するメッシュコード
+///
+/// [`MeshCode`]は2分の1・4分の1・8分の1のメッシュを同じ型で扱い、10桁のコードは
+/// 4分の1メッシュと5次メッシュのどちらにも解釈できます。この型は分割地域メッシュの
+/// 3つのレベルのメッシュしか保持できないため、4次メッシュを扱う処理で他のレベルの
+/// メッシュが紛れ込むことを防げます。
+///
+/// 値は[`FourthMesh::half`]・[`FourthMesh::quarter`]・[`FourthMesh::eighth`]・
+/// [`FourthMesh::parse`]・`TryFrom<MeshCode>`でのみ作成でき、いずれもレベルを検証します。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("533946112").unwrap();
+/// let fourth = FourthMesh::try_from(mesh).unwrap();
+/// assert_eq!(fourth.level(), MeshLevel::FourthHalf);
+/// assert_eq!(fourth.mesh(), mesh);
+///
+/// // 10桁は常に4分の1メッシュとして解釈する
+/// let quarter = FourthMesh::parse("5339461123").unwrap();
+/// assert_eq!(quarter.level(), MeshLevel::FourthQuarter);
+///
+/// assert!(FourthMesh::try_from(MeshCode::from_str("53394611").unwrap()).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FourthMesh {
+    /// 2分の1・4分の1・8分の1地域メッシュのいずれか
+    mesh: MeshCode,
+}
+
+/// メッシュが指定レベルであることを確かめる
+fn expect_level(mesh: MeshCode, level: MeshLevel) -> Result<FourthMesh> {
+    if mesh.level() != level {
+        return Err(MeshCodeError::InvalidFormat(format!(
+            "expected {}, got {}",
+            level.label(),
+            mesh.level().label()
+        )));
+    }
+    Ok(FourthMesh { mesh })
+}
+
+impl FourthMesh {
+    /// 2分の1メッシュから作成する（他のレベルは[`MeshCodeError::InvalidFormat`]）
+    pub fn half(mesh: MeshCode) -> Result<Self> {
+        expect_level(mesh, MeshLevel::FourthHalf)
+    }
+
+    /// 4分の1メッシュから作成する（他のレベルは[`MeshCodeError::InvalidFormat`]）
+    pub fn quarter(mesh: MeshCode) -> Result<Self> {
+        expect_level(mesh, MeshLevel::FourthQuarter)
+    }
+
+    /// 8分の1メッシュから作成する（他のレベルは[`MeshCodeError::InvalidFormat`]）
+    pub fn eighth(mesh: MeshCode) -> Result<Self> {
+        expect_level(mesh, MeshLevel::FourthEighth)
+    }
+
+    /// 9〜11桁のメッシュコード文字列をパースする
+    ///
+    /// 9桁は2分の1、10桁は4分の1、11桁は8分の1メッシュとして解釈します。
+    /// 10桁を5次メッシュと解釈することはありません。
+    pub fn parse(s: &str) -> Result<Self> {
+        let level = match s.len() {
+            9 => MeshLevel::FourthHalf,
+            10 => MeshLevel::FourthQuarter,
+            11 => MeshLevel::FourthEighth,
+            len => return Err(MeshCodeError::InvalidLevel(len)),
+        };
+        MeshCode::parse(s, Some(level)).map(|mesh| FourthMesh { mesh })
+    }
+
+    /// 元のメッシュコードを返す
+    pub fn mesh(&self) -> MeshCode {
+        self.mesh
+    }
+
+    /// メッシュレベルを返す
+    ///
+    /// [`MeshLevel::FourthHalf`]・[`MeshLevel::FourthQuarter`]・[`MeshLevel::FourthEighth`]の
+    /// いずれかです。
+    pub fn level(&self) -> MeshLevel {
+        self.mesh.level()
+    }
+}
+
+impl TryFrom<MeshCode> for FourthMesh {
+    type Error = MeshCodeError;
+
+    /// 分割地域メッシュから作成する（他のレベルはエラー）
+    fn try_from(mesh: MeshCode) -> Result<Self> {
+        match mesh.level() {
+            MeshLevel::FourthHalf | MeshLevel::FourthQuarter | MeshLevel::FourthEighth => {
+                Ok(FourthMesh { mesh })
+            }
+            level => Err(MeshCodeError::InvalidFormat(format!(
+                "{} is not a subdivided 4th-level mesh",
+                level.label()
+            ))),
+        }
+    }
+}
+
+impl From<FourthMesh> for MeshCode {
+    fn from(mesh: FourthMesh) -> Self {
+        mesh.mesh()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constructors() {
+        let half = MeshCode::from_str("533946112").unwrap();
+        let quarter = MeshCode::from_str("5339461123").unwrap();
+        let eighth = MeshCode::from_str("53394611234").unwrap();
+
+        assert_eq!(FourthMesh::half(half).unwrap().mesh(), half);
+        assert_eq!(FourthMesh::quarter(quarter).unwrap().mesh(), quarter);
+        assert_eq!(FourthMesh::eighth(eighth).unwrap().mesh(), eighth);
+
+        for (mesh, level) in [
+            (half, MeshLevel::FourthHalf),
+            (quarter, MeshLevel::FourthQuarter),
+            (eighth, MeshLevel::FourthEighth),
+        ] {
+            let fourth = FourthMesh::try_from(mesh).unwrap();
+            assert_eq!(fourth.level(), level);
+            assert_eq!(fourth.mesh(), mesh);
+            assert_eq!(MeshCode::from(fourth), mesh);
+            assert_eq!(FourthMesh::parse(&mesh.as_string()).unwrap(), fourth);
+        }
+    }
+
+    #[test]
+    fn test_rejects_mismatched_levels() {
+        let half = MeshCode::from_str("533946112").unwrap();
+        let third = MeshCode::from_str("53394611").unwrap();
+        let fifth = MeshCode::parse("5339461197", Some(MeshLevel::Fifth)).unwrap();
+
+        assert!(FourthMesh::quarter(half).is_err());
+        assert!(FourthMesh::eighth(half).is_err());
+        assert!(FourthMesh::half(third).is_err());
+        assert!(FourthMesh::try_from(third).is_err());
+        assert!(FourthMesh::try_from(fifth).is_err());
+        assert!(FourthMesh::quarter(fifth).is_err());
+
+        // 5次メッシュとしてしか解釈できない10桁コードは4分の1メッシュにならない
+        assert_eq!(
+            FourthMesh::parse("5339461197"),
+            Err(MeshCodeError::OutOfRange)
+        );
+        assert_eq!(
+            FourthMesh::parse("53394611"),
+            Err(MeshCodeError::InvalidLevel(8))
+        );
+    }
+}
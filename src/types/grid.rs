@@ -0,0 +1,141 @@
+//! メッシュの格子番号
+//!
+//! 各メッシュレベルのメッシュは、緯度・経度方向に等間隔な格子を構成します。
+//! 格子番号は、緯度は北緯0度から、経度は東経100度から数えたそのレベルの
+//! メッシュの個数です。隣接・移動などの計算を座標を介さずに整数演算で行うために使います。
+
+use crate::types::mesh_code::MeshCode;
+use crate::types::mesh_level::MeshLevel;
+
+impl MeshCode {
+    /// このメッシュの格子番号（緯度方向、経度方向）を返す
+    pub(crate) fn grid_index(&self) -> (i64, i64) {
+        let level = self.level();
+        let len = level.code_length() as u32;
+        let code = self.code();
+        let digit = |i: u32| ((code / 10u64.pow(len - 1 - i)) % 10) as i64;
+
+        let mut lat = digit(0) * 10 + digit(1);
+        let mut lon = digit(2) * 10 + digit(3);
+        if len >= 6 {
+            lat = lat * 8 + digit(4);
+            lon = lon * 8 + digit(5);
+        }
+        if len >= 8 {
+            lat = lat * 10 + digit(6);
+            lon = lon * 10 + digit(7);
+        }
+
+        match level {
+            MeshLevel::Fifth => {
+                lat = lat * 10 + digit(8);
+                lon = lon * 10 + digit(9);
+            }
+            MeshLevel::FourthHalf | MeshLevel::FourthQuarter | MeshLevel::FourthEighth => {
+                // 分割番号（1〜4）は南西=1、南東=2、北西=3、北東=4
+                for i in 8..len {
+                    let index = digit(i) - 1;
+                    lat = lat * 2 + index / 2;
+                    lon = lon * 2 + index % 2;
+                }
+            }
+            _ => {}
+        }
+
+        (lat, lon)
+    }
+
+    /// 格子番号からメッシュコードを作成する
+    ///
+    /// 格子番号がメッシュコードで表現できない場合（負の値や1次メッシュ番号が
+    /// 2桁を超える場合）はNoneを返します。
+    pub(crate) fn from_grid_index(level: MeshLevel, lat: i64, lon: i64) -> Option<MeshCode> {
+        if lat < 0 || lon < 0 {
+            return None;
+        }
+
+        let (mut lat, mut lon) = (lat as u64, lon as u64);
+        let mut suffix = 0u64;
+        let mut suffix_len = 0u32;
+        let mut push = |digit: u64, suffix: &mut u64| {
+            *suffix += digit * 10u64.pow(suffix_len);
+            suffix_len += 1;
+        };
+
+        match level {
+            MeshLevel::Fifth => {
+                push(lon % 10, &mut suffix);
+                push(lat % 10, &mut suffix);
+                lat /= 10;
+                lon /= 10;
+            }
+            MeshLevel::FourthHalf | MeshLevel::FourthQuarter | MeshLevel::FourthEighth => {
+                let depth = level.code_length() - 8;
+                for _ in 0..depth {
+                    push(1 + (lat % 2) * 2 + lon % 2, &mut suffix);
+                    lat /= 2;
+                    lon /= 2;
+                }
+            }
+            _ => {}
+        }
+
+        if level >= MeshLevel::Third {
+            push(lon % 10, &mut suffix);
+            push(lat % 10, &mut suffix);
+            lat /= 10;
+            lon /= 10;
+        }
+        if level >= MeshLevel::Second {
+            push(lon % 8, &mut suffix);
+            push(lat % 8, &mut suffix);
+            lat /= 8;
+            lon /= 8;
+        }
+        if lat > 99 || lon > 99 {
+            return None;
+        }
+
+        let first = lat * 100 + lon;
+        let code = first * 10u64.pow(suffix_len) + suffix;
+        MeshCode::new(level, code).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::mesh_to_bounds;
+
+    #[test]
+    fn test_grid_index_roundtrip() {
+        let cases = [
+            (MeshLevel::First, 5339),
+            (MeshLevel::Second, 533946),
+            (MeshLevel::Third, 53394611),
+            (MeshLevel::FourthHalf, 533946113),
+            (MeshLevel::FourthQuarter, 5339461142),
+            (MeshLevel::FourthEighth, 53394611423),
+            (MeshLevel::Fifth, 5339461197),
+            (MeshLevel::Third, 1000),
+        ];
+        for (level, code) in cases {
+            let mesh = MeshCode::new(level, code).unwrap();
+            let (lat, lon) = mesh.grid_index();
+            assert_eq!(MeshCode::from_grid_index(level, lat, lon), Some(mesh));
+
+            // 格子番号から求めた南西端がメッシュの境界と一致する
+            let bounds = mesh_to_bounds(mesh);
+            let sw_lat = lat as f64 * level.lat_size_degrees();
+            let sw_lon = 100.0 + lon as f64 * level.lon_size_degrees();
+            assert!((bounds.min_lat() - sw_lat).abs() < 1e-9, "{mesh}");
+            assert!((bounds.min_lon() - sw_lon).abs() < 1e-9, "{mesh}");
+        }
+    }
+
+    #[test]
+    fn test_from_grid_index_invalid() {
+        assert_eq!(MeshCode::from_grid_index(MeshLevel::Third, -1, 0), None);
+        assert_eq!(MeshCode::from_grid_index(MeshLevel::First, 100, 0), None);
+    }
+}
@@ -1,4 +1,8 @@
+use crate::convert::mesh_to_bounds;
 use crate::error::{MeshCodeError, Result};
+use crate::types::bounding_box::{BoundingBox, JAPAN_BOUNDS};
+use crate::types::coordinate::Coordinate;
+use crate::types::direction::Direction;
 use crate::types::mesh_level::MeshLevel;
 use alloc::format;
 use alloc::string::{String, ToString};
@@ -10,6 +14,9 @@ use core::fmt;
 /// 下位56ビットにメッシュコード値を格納します。
 /// Copy traitを実装しているため、効率的な値渡しが可能です。
 ///
+/// 順序（`Ord`）は内部表現のu64値に基づきます。同じレベルのメッシュは
+/// コード値の昇順に並び、レベルが異なる場合は粗いレベルが先になります。
+///
 /// # シリアライズ（`serde`フィーチャー）
 ///
 /// `serde`フィーチャー有効時は、メッシュコード文字列（例: `"53394611"`）として
@@ -27,7 +34,7 @@ use core::fmt;
 /// assert_eq!(mesh.level(), MeshLevel::First);
 /// assert_eq!(mesh.as_string(), "5339");
 /// ```
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(into = "String", try_from = "String"))]
 pub struct MeshCode {
@@ -38,16 +45,39 @@ impl MeshCode {
     /// メッシュレベルとコード値から新しいメッシュコードを作成する
     ///
     /// コード値がレベルの桁数・番号規則に反する場合は
-    /// [`MeshCodeError::OutOfRange`]を返します。
+    /// [`MeshCodeError::OutOfRange`]を返します。レベルの桁数より短いコード値は
+    /// 先頭をゼロで埋めたコードとして受け付けます（例: 3次メッシュの`5339`は
+    /// `"00005339"`）。有効桁数まで検証する場合は[`crate::try_mesh_to_bounds`]を使用します。
     ///
     /// # 引数
     /// * `level` - メッシュレベル
     /// * `code` - メッシュコード値
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// assert!(MeshCode::new(MeshLevel::Third, 53394611).is_ok());
+    /// assert_eq!(
+    ///     MeshCode::new(MeshLevel::Third, 533946111),
+    ///     Err(MeshCodeError::OutOfRange)
+    /// );
+    /// ```
     pub fn new(level: MeshLevel, code: u64) -> Result<Self> {
         Self::validate(level, code)?;
+        Ok(Self::new_unchecked(level, code))
+    }
+
+    /// 検証済みのメッシュレベルとコード値からメッシュコードを作成する
+    ///
+    /// 呼び出し側がコード値の有効性を保証する場合に使用します。
+    /// デバッグビルドでは[`MeshCode::new`]と同じ検証を行います。
+    pub(crate) fn new_unchecked(level: MeshLevel, code: u64) -> Self {
+        debug_assert!(Self::validate(level, code).is_ok());
         let level_bits = (level.as_u8() as u64) << 56;
         let value = level_bits | (code & 0x00FF_FFFF_FFFF_FFFF);
-        Ok(MeshCode { value })
+        MeshCode { value }
     }
 
     /// コード値がメッシュレベルの規則に適合するか検証する
@@ -99,6 +129,33 @@ impl MeshCode {
     /// ```
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s, None)
+    }
+
+    /// レベルを指定して文字列からメッシュコードをパースする
+    ///
+    /// `expected`が`Some`の場合は、文字列の桁数がそのレベルの桁数と一致することを
+    /// 検証し、桁数からの推定を行わずにそのレベルとして解釈します。10桁コードの
+    /// 4分の1メッシュ／5次メッシュのように、文脈からレベルが分かっている場合に使用します。
+    /// `None`の場合は[`MeshCode::from_str`]と同じ規則でレベルを判定します。
+    ///
+    /// # 引数
+    /// * `s` - メッシュコード文字列
+    /// * `expected` - 期待するメッシュレベル
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// // 推定では4分の1メッシュになる10桁コードを5次メッシュとして解釈する
+    /// let mesh = MeshCode::parse("5339461111", Some(MeshLevel::Fifth)).unwrap();
+    /// assert_eq!(mesh.level(), MeshLevel::Fifth);
+    ///
+    /// // 桁数が一致しない場合はエラー
+    /// assert!(MeshCode::parse("53394611", Some(MeshLevel::Second)).is_err());
+    /// ```
+    pub fn parse(s: &str, expected: Option<MeshLevel>) -> Result<Self> {
         if s.is_empty() {
             return Err(MeshCodeError::InvalidFormat("Empty string".to_string()));
         }
@@ -112,7 +169,17 @@ impl MeshCode {
             }
         }
 
-        let level = MeshLevel::from_code_string(s)?;
+        let level = match expected {
+            Some(level) if level.code_length() != s.len() => {
+                return Err(MeshCodeError::InvalidFormat(format!(
+                    "expected {} digits for {level:?}, got {}",
+                    level.code_length(),
+                    s.len()
+                )));
+            }
+            Some(level) => level,
+            None => MeshLevel::from_code_string(s)?,
+        };
 
         let code = s.parse::<u64>().map_err(|_| {
             MeshCodeError::InvalidFormat("Failed to parse numeric code".to_string())
@@ -121,6 +188,67 @@ impl MeshCode {
         Self::new(level, code)
     }
 
+    /// 座標を手がかりに10桁コードを4分の1メッシュと5次メッシュのどちらかに判定する
+    ///
+    /// 10桁コードを[`MeshLevel::FourthQuarter`]と[`MeshLevel::Fifth`]の両方として
+    /// パースし、境界に`hint`を含む方を返します。どちらのメッシュにも含まれない場合や、
+    /// 両方に含まれて判定できない場合は[`MeshCodeError::InvalidFormat`]を返します。
+    /// 片方のレベルとしてしかパースできない場合も、そのメッシュが`hint`を含む必要があります。
+    ///
+    /// # 引数
+    /// * `code_str` - 10桁のメッシュコード文字列
+    /// * `hint` - メッシュ内にあることが分かっている座標
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let hint = Coordinate::new(35.6812, 139.7671).unwrap();
+    /// let fifth = coord_to_mesh(hint, MeshLevel::Fifth).unwrap();
+    /// let mesh = MeshCode::disambiguate_10digit(&fifth.as_string(), hint).unwrap();
+    /// assert_eq!(mesh.level(), MeshLevel::Fifth);
+    /// ```
+    pub fn disambiguate_10digit(code_str: &str, hint: Coordinate) -> Result<MeshCode> {
+        let quarter = Self::parse(code_str, Some(MeshLevel::FourthQuarter));
+        let fifth = Self::parse(code_str, Some(MeshLevel::Fifth));
+        if let (Err(e), Err(_)) = (&quarter, &fifth) {
+            return Err(e.clone());
+        }
+
+        let contains = |mesh: &Result<MeshCode>| {
+            mesh.as_ref()
+                .is_ok_and(|m| mesh_to_bounds(*m).contains(hint))
+        };
+        match (contains(&quarter), contains(&fifth)) {
+            (true, false) => quarter,
+            (false, true) => fifth,
+            (true, true) => Err(MeshCodeError::InvalidFormat(format!(
+                "{code_str} contains the hint both as FourthQuarter and Fifth"
+            ))),
+            (false, false) => Err(MeshCodeError::InvalidFormat(format!(
+                "{code_str} does not contain the hint as FourthQuarter or Fifth"
+            ))),
+        }
+    }
+
+    /// メッシュコードの桁数からメッシュレベルを判定する
+    ///
+    /// [`MeshLevel::from_code_length`]の簡易呼び出しです。10桁は4分の1メッシュと
+    /// 判定されます（5次メッシュとの区別には[`MeshLevel::from_code_string`]を使用）。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// assert_eq!(MeshCode::level_from_length(8).unwrap(), MeshLevel::Third);
+    /// assert!(MeshCode::level_from_length(5).is_err());
+    /// ```
+    pub fn level_from_length(len: usize) -> Result<MeshLevel> {
+        MeshLevel::from_code_length(len)
+    }
+
     /// このメッシュコードのレベルを返す
     pub fn level(&self) -> MeshLevel {
         let level_byte = (self.value >> 56) as u8;
@@ -141,6 +269,571 @@ impl MeshCode {
         let width = level.code_length();
         format!("{code:0width$}")
     }
+
+    /// コード文字列をスタック上のバッファに書き出し、`&str`としてクロージャに渡す
+    ///
+    /// [`MeshCode::as_string`]と同じ文字列ですが、ヒープ確保を行いません。
+    /// `HashMap<String, _>`のようにコード文字列をキーとするコレクションを
+    /// 検索する場合に、検索ごとに`String`を作らずに済みます。
+    ///
+    /// # 引数
+    /// * `f` - コード文字列を受け取るクロージャ
+    ///
+    /// # 戻り値
+    /// クロージャの戻り値
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("53394611").unwrap();
+    /// assert_eq!(mesh.with_str(|s| s.len()), 8);
+    /// assert!(mesh.with_str(|s| s == "53394611"));
+    /// ```
+    pub fn with_str<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        // 最長の8分の1メッシュ（11桁）が収まるバッファ
+        let mut buf = [b'0'; 11];
+        let len = self.level().code_length();
+        let mut code = self.code();
+        for digit in buf[..len].iter_mut().rev() {
+            *digit = b'0' + (code % 10) as u8;
+            code /= 10;
+        }
+        let s = core::str::from_utf8(&buf[..len]).expect("数字のみのバッファは常にUTF-8");
+        f(s)
+    }
+
+    /// レベル番号を前置した表示用のラッパーを返す
+    ///
+    /// `Display`で`[3]53394611`のように`[レベル番号]コード`の形式で出力します。
+    /// レベル番号は[`MeshLevel::as_u8`]の値です。ログ出力など、レベルを明示したい
+    /// 場面で使用します。通常の`Display`の出力は変わりません。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("53394611").unwrap();
+    /// assert_eq!(mesh.labeled().to_string(), "[3]53394611");
+    /// assert_eq!(mesh.to_string(), "53394611");
+    /// ```
+    pub fn labeled(&self) -> LabeledMeshCode {
+        LabeledMeshCode(*self)
+    }
+
+    /// レベルを含む固定長の正規化文字列を返す
+    ///
+    /// 先頭1桁に[`MeshLevel::as_u8`]のレベル番号を置き、続く11桁にメッシュコードを
+    /// 左詰めで書いて残りを`0`で埋めた、合計12桁の文字列です。最長の8分の1メッシュ
+    /// （11桁）でもそのまま収まり、4分の1メッシュと5次メッシュのように同じ桁数の
+    /// レベルもレベル番号で区別できます。文字列の辞書順はレベル番号順、
+    /// 同じレベル内ではコード順になるため、データベースのキーとしてそのまま並べ替えに使えます。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("53394611").unwrap();
+    /// assert_eq!(mesh.to_canonical11(), "353394611000");
+    /// ```
+    pub fn to_canonical11(&self) -> String {
+        format!("{}{:0<11}", self.level().as_u8(), self.as_string())
+    }
+
+    /// [`MeshCode::to_canonical11`]の形式の文字列からメッシュコードを復元する
+    ///
+    /// 12桁の数字でない場合、レベル番号が無効な場合、コードの後ろの埋め草が
+    /// `0`でない場合はエラーを返します。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_canonical11("753394611110").unwrap();
+    /// assert_eq!(mesh.level(), MeshLevel::Fifth);
+    /// assert_eq!(mesh.as_string(), "5339461111");
+    ///
+    /// assert!(MeshCode::from_canonical11("75339461111").is_err());
+    /// ```
+    pub fn from_canonical11(s: &str) -> Result<Self> {
+        if s.len() != 12 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(MeshCodeError::InvalidFormat(format!(
+                "canonical form must be 12 digits: {s}"
+            )));
+        }
+
+        let level = MeshLevel::from_u8(s.as_bytes()[0] - b'0')?;
+        let (code, padding) = s[1..].split_at(level.code_length());
+        if padding.bytes().any(|b| b != b'0') {
+            return Err(MeshCodeError::InvalidFormat(format!(
+                "non-zero padding in canonical form: {s}"
+            )));
+        }
+
+        Self::parse(code, Some(level))
+    }
+
+    /// メッシュの階層ごとに区切り文字`-`を挿入した文字列表現を返す
+    ///
+    /// 1次（4桁）・2次（2桁）・3次（2桁）の各区画の間に区切りを入れ、
+    /// 分割地域メッシュは分割番号1桁ごと、5次メッシュは末尾2桁を1区画とします。
+    /// 帳票など人が読むための表示に使用します。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("533946113").unwrap();
+    /// assert_eq!(mesh.as_grouped_string(), "5339-46-11-3");
+    /// ```
+    pub fn as_grouped_string(&self) -> String {
+        let digits = self.as_string();
+        let group_end: &[usize] = match self.level() {
+            MeshLevel::First => &[4],
+            MeshLevel::Second => &[4, 6],
+            MeshLevel::Third => &[4, 6, 8],
+            MeshLevel::FourthHalf => &[4, 6, 8, 9],
+            MeshLevel::FourthQuarter => &[4, 6, 8, 9, 10],
+            MeshLevel::FourthEighth => &[4, 6, 8, 9, 10, 11],
+            MeshLevel::Fifth => &[4, 6, 8, 10],
+        };
+
+        let mut result = String::with_capacity(digits.len() + group_end.len());
+        let mut start = 0;
+        for &end in group_end {
+            if start > 0 {
+                result.push('-');
+            }
+            result.push_str(&digits[start..end]);
+            start = end;
+        }
+        result
+    }
+
+    /// CSV出力用のフィールドを返す
+    ///
+    /// serdeを使わずにメッシュ一覧を書き出すための補助メソッドです。
+    /// フィールドの並びは[`MeshCode::csv_header`]の列名と対応し、
+    /// （メッシュコード文字列、レベル番号、南端緯度、西端経度、北端緯度、東端経度）です。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("53394611").unwrap();
+    /// let (code, level, min_lat, min_lon, max_lat, max_lon) = mesh.to_csv_fields();
+    /// println!("{code},{level},{min_lat},{min_lon},{max_lat},{max_lon}");
+    /// ```
+    pub fn to_csv_fields(&self) -> (String, u8, f64, f64, f64, f64) {
+        let bounds = mesh_to_bounds(*self);
+        (
+            self.as_string(),
+            self.level().as_u8(),
+            bounds.min_lat(),
+            bounds.min_lon(),
+            bounds.max_lat(),
+            bounds.max_lon(),
+        )
+    }
+
+    /// 1次メッシュ内での南西端の相対位置を返す
+    ///
+    /// 所属する1次メッシュの南西端を原点とし、1次メッシュの大きさを1としたときの
+    /// このメッシュの南西端の位置を（緯度方向、経度方向）で返します。
+    /// 各値は0.0以上1.0未満です。メッシュ間でデータを補間する際に利用できます。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("533946").unwrap();
+    /// let (lat_frac, lon_frac) = mesh.fractional_position();
+    /// assert!((lat_frac - 4.0 / 8.0).abs() < 1e-9);
+    /// assert!((lon_frac - 6.0 / 8.0).abs() < 1e-9);
+    /// ```
+    pub fn fractional_position(&self) -> (f64, f64) {
+        let bounds = mesh_to_bounds(*self);
+        let first_lat_size = MeshLevel::First.lat_size_degrees();
+        let first_lon_size = MeshLevel::First.lon_size_degrees();
+
+        // 1次メッシュの南西端（緯度は2/3度、経度は1度刻み）
+        let code = self.code() / 10u64.pow(self.level().code_length() as u32 - 4);
+        let first_lat = (code / 100) as f64 / 1.5;
+        let first_lon = (code % 100) as f64 + 100.0;
+
+        (
+            (bounds.min_lat() - first_lat) / first_lat_size,
+            (bounds.min_lon() - first_lon) / first_lon_size,
+        )
+    }
+
+    /// 日本のメッシュ範囲の端にあるメッシュかどうかを判定する
+    ///
+    /// 8方向のいずれかの隣接メッシュが範囲（北緯20〜46度、東経122〜154度）の外に
+    /// なる場合に`true`を返します。すなわち[`crate::neighbors`]の結果が8個未満の
+    /// メッシュで、隣接メッシュを使う処理が不完全になることを示します。
+    /// 陸域・水域の区別は行いません。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let tokyo = MeshCode::from_str("53394611").unwrap();
+    /// assert!(!tokyo.is_edge_mesh());
+    /// ```
+    pub fn is_edge_mesh(&self) -> bool {
+        crate::operations::neighbors(*self).len() < 8
+    }
+
+    /// 地理的な並び替え用のキーを返す
+    ///
+    /// `Ord`による順序は内部表現（上位にレベル）に基づくため、レベルが混在すると
+    /// 地理的な位置と関係なく並びます。このキーはメッシュの南西端を5次メッシュ
+    /// （緯度3秒、経度4.5秒）の格子で量子化した（緯度方向番号、経度方向番号）で、
+    /// レベルに関係なく南から北、同じ緯度では西から東の順に並べられます。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mut meshes = vec![
+    ///     MeshCode::from_str("5339").unwrap(),
+    ///     MeshCode::from_str("52394611").unwrap(),
+    /// ];
+    /// meshes.sort_by_key(|m| m.geo_sort_key());
+    /// assert_eq!(meshes[0].as_string(), "52394611");
+    /// ```
+    pub fn geo_sort_key(&self) -> (u32, u32) {
+        let bounds = mesh_to_bounds(*self);
+        let lat_unit = MeshLevel::Fifth.lat_size_degrees();
+        let lon_unit = MeshLevel::Fifth.lon_size_degrees();
+
+        // 格子上の南西端が浮動小数点誤差で1つ手前の番号にならないよう微小量を加える
+        let lat_index = crate::utils::math::floor(bounds.min_lat() / lat_unit + 1e-6);
+        let lon_index = crate::utils::math::floor(bounds.min_lon() / lon_unit + 1e-6);
+        (lat_index as u32, lon_index as u32)
+    }
+
+    /// メッシュのおおよその面積を平方メートルで返す
+    ///
+    /// 南北方向の長さに、メッシュ中央の緯度で補正した東西方向の長さを掛けて求めます。
+    /// 同じレベルでも高緯度のメッシュほど面積は小さくなります。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("53394611").unwrap();
+    /// let area = mesh.area_square_meters();
+    /// assert!((area - 1_060_000.0).abs() < 20_000.0);
+    /// ```
+    pub fn area_square_meters(&self) -> f64 {
+        mesh_to_bounds(*self).area_square_meters()
+    }
+
+    /// メッシュを囲む円の中心座標と半径（メートル）を返す
+    ///
+    /// 中心はメッシュの中心座標、半径は中心から4隅までのHaversine距離の最大値です。
+    /// 円による大まかな絞り込み（カリング）に使用します。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("53394611").unwrap();
+    /// let (center, radius) = mesh.bounding_circle();
+    /// assert_eq!(center, mesh_to_center(mesh));
+    /// assert!(radius > 500.0 && radius < 1000.0);
+    /// ```
+    pub fn bounding_circle(&self) -> (Coordinate, f64) {
+        use crate::utils::distance::haversine_distance;
+
+        let bounds = mesh_to_bounds(*self);
+        let center = bounds.center();
+        let corners = [
+            (bounds.min_lat(), bounds.min_lon()),
+            (bounds.min_lat(), bounds.max_lon()),
+            (bounds.max_lat(), bounds.min_lon()),
+            (bounds.max_lat(), bounds.max_lon()),
+        ];
+        let radius = corners
+            .iter()
+            .map(|&(lat, lon)| haversine_distance(center, Coordinate::new_unchecked(lat, lon)))
+            .fold(0.0, f64::max);
+        (center, radius)
+    }
+
+    /// メッシュの4隅の座標を`(緯度, 経度)`のタプルで返す
+    ///
+    /// 南西・南東・北東・北西の順（反時計回り）です。[`Coordinate`]型を使えない
+    /// FFIの境界やJSONへの書き出しで、素の数値として扱うための補助メソッドです。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("5339").unwrap();
+    /// let [sw, se, ne, nw] = mesh.corner_tuples();
+    /// assert_eq!(sw, (35.0 + 20.0 / 60.0, 139.0));
+    /// assert_eq!(se.1, 140.0);
+    /// assert_eq!(ne.0, 36.0);
+    /// assert_eq!(nw, (36.0, 139.0));
+    /// ```
+    pub fn corner_tuples(&self) -> [(f64, f64); 4] {
+        let bounds = mesh_to_bounds(*self);
+        [
+            (bounds.min_lat(), bounds.min_lon()),
+            (bounds.min_lat(), bounds.max_lon()),
+            (bounds.max_lat(), bounds.max_lon()),
+            (bounds.max_lat(), bounds.min_lon()),
+        ]
+    }
+
+    /// [`MeshCode::to_csv_fields`]に対応するCSVの列名を返す
+    pub fn csv_header() -> [&'static str; 6] {
+        [
+            "mesh_code",
+            "level",
+            "min_lat",
+            "min_lon",
+            "max_lat",
+            "max_lon",
+        ]
+    }
+
+    /// 分割番号を指定して1段階細かい分割地域メッシュを取得する
+    ///
+    /// 3次メッシュからは2分の1、2分の1からは4分の1、4分の1からは8分の1の
+    /// メッシュを返します。分割番号は南西=1、南東=2、北西=3、北東=4です。
+    /// 5次メッシュなど他のレベルの子は[`MeshCode::child_at_level`]で取得します。
+    ///
+    /// # 引数
+    /// * `sub_index` - 分割番号（1〜4）
+    ///
+    /// # 戻り値
+    /// 子メッシュコード。分割地域メッシュの子を持たないレベルの場合は
+    /// [`MeshCodeError::InvalidFormat`]、分割番号が範囲外の場合は
+    /// [`MeshCodeError::OutOfRange`]
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let third = MeshCode::from_str("53394611").unwrap();
+    /// assert_eq!(third.child(4).unwrap().as_string(), "533946114");
+    /// assert!(third.child(5).is_err());
+    /// ```
+    pub fn child(&self, sub_index: u32) -> Result<MeshCode> {
+        let level = match self.level() {
+            MeshLevel::Third => MeshLevel::FourthHalf,
+            MeshLevel::FourthHalf => MeshLevel::FourthQuarter,
+            MeshLevel::FourthQuarter => MeshLevel::FourthEighth,
+            level => {
+                return Err(MeshCodeError::InvalidFormat(format!(
+                    "{} has no subdivision children",
+                    level.label()
+                )))
+            }
+        };
+        self.child_at_level(sub_index, level)
+    }
+
+    /// 子のレベルと番号を指定して1段階細かいメッシュを取得する
+    ///
+    /// `level`の親レベル（[`MeshLevel::parent`]）がこのメッシュのレベルと
+    /// 一致する必要があります。`sub_index`はこのメッシュのコードの末尾に付加する
+    /// 番号で、レベルごとに次の範囲を受け付けます。
+    ///
+    /// - 2次メッシュ: 0〜77（各桁0〜7）
+    /// - 3次メッシュ・5次メッシュ: 0〜99
+    /// - 分割地域メッシュ（2分の1・4分の1・8分の1）: 1〜4
+    ///
+    /// # 引数
+    /// * `sub_index` - 子メッシュの番号
+    /// * `level` - 子メッシュのレベル
+    ///
+    /// # 戻り値
+    /// 子メッシュコード。`level`がこのメッシュの子のレベルでない場合は
+    /// [`MeshCodeError::InvalidFormat`]、番号が範囲外の場合は
+    /// [`MeshCodeError::OutOfRange`]
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let third = MeshCode::from_str("53394611").unwrap();
+    /// let fifth = third.child_at_level(97, MeshLevel::Fifth).unwrap();
+    /// assert_eq!(fifth.as_string(), "5339461197");
+    ///
+    /// let half = third.child(1).unwrap();
+    /// let quarter = half.child_at_level(2, MeshLevel::FourthQuarter).unwrap();
+    /// assert_eq!(quarter.as_string(), "5339461112");
+    /// ```
+    pub fn child_at_level(&self, sub_index: u32, level: MeshLevel) -> Result<MeshCode> {
+        if level.parent() != Some(self.level()) {
+            return Err(MeshCodeError::InvalidFormat(format!(
+                "{} is not a child level of {}",
+                level.label(),
+                self.level().label()
+            )));
+        }
+
+        let digits = (level.code_length() - self.level().code_length()) as u32;
+        let scale = 10u64.pow(digits);
+        let sub_index = sub_index as u64;
+        if sub_index >= scale {
+            return Err(MeshCodeError::OutOfRange);
+        }
+        MeshCode::new(level, self.code() * scale + sub_index)
+    }
+
+    /// 指定方向へ`count`個分移動した位置にある同じレベルのメッシュを取得する
+    ///
+    /// 隣接メッシュを`count`回たどる代わりに、格子番号の整数演算で一度に求めます。
+    /// `count`が負の場合は逆方向へ移動します。
+    ///
+    /// # 引数
+    /// * `direction` - 移動する方向
+    /// * `count` - 移動するメッシュの個数
+    ///
+    /// # 戻り値
+    /// 移動先のメッシュコード、または日本の範囲外の場合はNone
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("53394611").unwrap();
+    /// assert_eq!(mesh.step(Direction::East, 3).unwrap().as_string(), "53394614");
+    /// assert_eq!(mesh.step(Direction::East, -1).unwrap().as_string(), "53394610");
+    /// ```
+    pub fn step(&self, direction: Direction, count: i64) -> Option<MeshCode> {
+        let level = self.level();
+        let (dx, dy) = direction.offset();
+        let (lat_index, lon_index) = self.grid_index();
+        let lat_index = lat_index.checked_add((dy as i64).checked_mul(count)?)?;
+        let lon_index = lon_index.checked_add((dx as i64).checked_mul(count)?)?;
+
+        let center_lat = (lat_index as f64 + 0.5) * level.lat_size_degrees();
+        let center_lon = 100.0 + (lon_index as f64 + 0.5) * level.lon_size_degrees();
+        if !JAPAN_BOUNDS.contains(Coordinate::new_unchecked(center_lat, center_lon)) {
+            return None;
+        }
+
+        MeshCode::from_grid_index(level, lat_index, lon_index)
+    }
+
+    /// 他のメッシュが8近傍で隣接している場合、その方向を返す
+    ///
+    /// 格子番号で比較するため、親メッシュの境界をまたぐ場合も正確に判定できます。
+    /// レベルが異なる場合や、同じメッシュ・隣接していないメッシュの場合はNoneを返します。
+    ///
+    /// # 引数
+    /// * `other` - 比較するメッシュコード
+    ///
+    /// # 戻り値
+    /// `self`から見た`other`の方向、または隣接していない場合はNone
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("53394611").unwrap();
+    /// let east = MeshCode::from_str("53394612").unwrap();
+    /// assert_eq!(mesh.is_neighbor_of(&east), Some(Direction::East));
+    /// assert_eq!(east.is_neighbor_of(&mesh), Some(Direction::West));
+    /// ```
+    pub fn is_neighbor_of(&self, other: &MeshCode) -> Option<Direction> {
+        if self.level() != other.level() {
+            return None;
+        }
+
+        let (lat_a, lon_a) = self.grid_index();
+        let (lat_b, lon_b) = other.grid_index();
+        let (dx, dy) = (lon_b - lon_a, lat_b - lat_a);
+        if dx.abs() > 1 || dy.abs() > 1 {
+            return None;
+        }
+        Direction::ALL
+            .iter()
+            .copied()
+            .find(|dir| dir.offset() == (dx as i32, dy as i32))
+    }
+
+    /// 2つのメッシュが指定レベルで同じ祖先メッシュに含まれるか判定する
+    ///
+    /// 両方のメッシュを[`crate::to_level`]で`at_level`に変換して比較します。
+    /// どちらかのメッシュが`at_level`より粗い場合や、`at_level`が祖先のレベルでない場合
+    /// （例: 5次メッシュと2分の1）はfalseを返します。`at_level`が両方のメッシュと
+    /// 同じレベルの場合は、同じメッシュかどうかの判定になります。
+    ///
+    /// # 引数
+    /// * `other` - 比較するメッシュコード
+    /// * `at_level` - 比較する祖先のレベル
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let a = MeshCode::from_str("53394611").unwrap();
+    /// let b = MeshCode::from_str("53394699").unwrap();
+    /// assert!(a.shares_parent(&b, MeshLevel::Second));
+    /// assert!(!a.shares_parent(&b, MeshLevel::Third));
+    /// ```
+    pub fn shares_parent(&self, other: &MeshCode, at_level: MeshLevel) -> bool {
+        use crate::operations::to_level;
+
+        match (to_level(*self, at_level), to_level(*other, at_level)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// メッシュの範囲がバウンディングボックスと重なるか判定する
+    ///
+    /// [`mesh_to_bounds`]の矩形と`bbox`の重なりで判定します。辺や頂点が接しているだけで
+    /// 重なる面積がない場合はfalseを返します。このため、メッシュ境界に揃えた
+    /// バウンディングボックスに対して、その外側に隣接するメッシュは含まれません。
+    ///
+    /// # 引数
+    /// * `bbox` - 判定対象のバウンディングボックス
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let mesh = MeshCode::from_str("53394611").unwrap();
+    /// let view = BoundingBox::new(
+    ///     Coordinate::new(35.67, 139.76).unwrap(),
+    ///     Coordinate::new(35.70, 139.80).unwrap(),
+    /// );
+    /// assert!(mesh.intersects_bbox(&view));
+    /// ```
+    pub fn intersects_bbox(&self, bbox: &BoundingBox) -> bool {
+        let bounds = mesh_to_bounds(*self);
+        bounds.min_lat() < bbox.max_lat()
+            && bbox.min_lat() < bounds.max_lat()
+            && bounds.min_lon() < bbox.max_lon()
+            && bbox.min_lon() < bounds.max_lon()
+    }
 }
 
 impl core::str::FromStr for MeshCode {
@@ -165,6 +858,14 @@ impl TryFrom<String> for MeshCode {
     }
 }
 
+impl TryFrom<&str> for MeshCode {
+    type Error = MeshCodeError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        MeshCode::from_str(s)
+    }
+}
+
 impl fmt::Debug for MeshCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("MeshCode")
@@ -180,6 +881,18 @@ impl fmt::Display for MeshCode {
     }
 }
 
+/// レベル番号付きでメッシュコードを表示するラッパー
+///
+/// [`MeshCode::labeled`]で作成します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LabeledMeshCode(pub MeshCode);
+
+impl fmt::Display for LabeledMeshCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]{}", self.0.level().as_u8(), self.0.as_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,6 +905,31 @@ mod tests {
         assert_eq!(mesh.as_string(), "5339");
     }
 
+    #[test]
+    fn test_new_validates_code_length() {
+        // レベルの桁数を超えるコード値
+        assert_eq!(
+            MeshCode::new(MeshLevel::Third, 533946111),
+            Err(MeshCodeError::OutOfRange)
+        );
+        assert_eq!(
+            MeshCode::new(MeshLevel::First, 53394),
+            Err(MeshCodeError::OutOfRange)
+        );
+        assert_eq!(
+            MeshCode::new(MeshLevel::FourthEighth, 533946111111),
+            Err(MeshCodeError::OutOfRange)
+        );
+
+        // 桁数の合う妥当なコード値
+        let mesh = MeshCode::new(MeshLevel::Third, 53394611).unwrap();
+        assert_eq!(mesh, MeshCode::from_str("53394611").unwrap());
+
+        // 短いコード値は先頭をゼロで埋めたコードになる
+        let short = MeshCode::new(MeshLevel::Third, 5339).unwrap();
+        assert_eq!(short.as_string(), "00005339");
+    }
+
     #[test]
     fn test_mesh_code_from_str() {
         let mesh = MeshCode::from_str("5339").unwrap();
@@ -210,6 +948,498 @@ mod tests {
         assert!(MeshCode::from_str("12345").is_err());
     }
 
+    #[test]
+    fn test_five_digit_error_mentions_valid_lengths() {
+        let err = MeshCode::from_str("53394").unwrap_err();
+        assert!(err.to_string().contains("4, 6, 8, 9, 10 or 11"));
+    }
+
+    #[test]
+    fn test_to_csv_fields() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let (code, level, min_lat, min_lon, max_lat, max_lon) = mesh.to_csv_fields();
+        assert_eq!(MeshCode::csv_header().len(), 6);
+        assert_eq!(code, "53394611");
+        assert_eq!(level, 3);
+
+        let bounds = mesh_to_bounds(mesh);
+        assert_eq!(min_lat, bounds.min_lat());
+        assert_eq!(min_lon, bounds.min_lon());
+        assert_eq!(max_lat, bounds.max_lat());
+        assert_eq!(max_lon, bounds.max_lon());
+    }
+
+    #[test]
+    fn test_fractional_position_center() {
+        // 南西端が1次メッシュ5339の中心にある2次メッシュ
+        let mesh = MeshCode::from_str("533944").unwrap();
+        let (lat_frac, lon_frac) = mesh.fractional_position();
+        assert!((lat_frac - 0.5).abs() < 1e-9);
+        assert!((lon_frac - 0.5).abs() < 1e-9);
+
+        // 北東端が中心にある3次メッシュは、中心から自身の大きさを引いた位置
+        let third = MeshCode::from_str("53393399").unwrap();
+        let (lat_frac, lon_frac) = third.fractional_position();
+        let lat_ratio = MeshLevel::Third.lat_size_degrees() / MeshLevel::First.lat_size_degrees();
+        let lon_ratio = MeshLevel::Third.lon_size_degrees() / MeshLevel::First.lon_size_degrees();
+        assert!((lat_frac - (0.5 - lat_ratio)).abs() < 1e-9);
+        assert!((lon_frac - (0.5 - lon_ratio)).abs() < 1e-9);
+
+        let sw_corner = MeshCode::from_str("53390000").unwrap();
+        assert_eq!(sw_corner.fractional_position(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_is_edge_mesh() {
+        let tokyo = MeshCode::from_str("53394611").unwrap();
+        assert!(!tokyo.is_edge_mesh());
+
+        let coord = crate::types::Coordinate::new(45.999, 141.5).unwrap();
+        let north_edge = crate::convert::coord_to_mesh(coord, MeshLevel::Third).unwrap();
+        assert!(north_edge.is_edge_mesh());
+    }
+
+    #[test]
+    fn test_parse_with_expected_level() {
+        let cases = [
+            ("5339", MeshLevel::First),
+            ("533946", MeshLevel::Second),
+            ("53394611", MeshLevel::Third),
+            ("533946113", MeshLevel::FourthHalf),
+            ("5339461134", MeshLevel::FourthQuarter),
+            ("53394611342", MeshLevel::FourthEighth),
+            ("5339461197", MeshLevel::Fifth),
+        ];
+        for (code, level) in cases {
+            let mesh = MeshCode::parse(code, Some(level)).unwrap();
+            assert_eq!(mesh.level(), level);
+            assert_eq!(mesh.as_string(), code);
+        }
+
+        // 桁数とレベルが一致しない
+        assert!(MeshCode::parse("53394611", Some(MeshLevel::FourthHalf)).is_err());
+        assert!(MeshCode::parse("5339461111", Some(MeshLevel::FourthEighth)).is_err());
+    }
+
+    #[test]
+    fn test_parse_resolves_ambiguous_ten_digits() {
+        let guessed = MeshCode::parse("5339461111", None).unwrap();
+        assert_eq!(guessed.level(), MeshLevel::FourthQuarter);
+
+        let fifth = MeshCode::parse("5339461111", Some(MeshLevel::Fifth)).unwrap();
+        assert_eq!(fifth.level(), MeshLevel::Fifth);
+        assert_ne!(fifth, guessed);
+    }
+
+    #[test]
+    fn test_geo_sort_key() {
+        let south = MeshCode::from_str("52394611").unwrap();
+        let north = MeshCode::from_str("5339").unwrap();
+        assert!(south.geo_sort_key() < north.geo_sort_key());
+        // 内部表現の順序ではレベルの粗い1次メッシュが先になる
+        assert!(north < south);
+
+        // 同じ南西端を持つメッシュはレベルによらず同じキー
+        let first = MeshCode::from_str("5339").unwrap();
+        let fifth = MeshCode::new(MeshLevel::Fifth, 5339000000).unwrap();
+        assert_eq!(first.geo_sort_key(), fifth.geo_sort_key());
+
+        // 同じ緯度帯では西から東
+        let west = MeshCode::from_str("53394611").unwrap();
+        let east = MeshCode::from_str("53394612").unwrap();
+        assert!(west.geo_sort_key() < east.geo_sort_key());
+    }
+
+    #[test]
+    fn test_string_conversions() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+
+        let s: String = mesh.into();
+        assert_eq!(s, "53394611");
+        assert_eq!(MeshCode::try_from(s).unwrap(), mesh);
+        assert_eq!(MeshCode::try_from("53394611").unwrap(), mesh);
+
+        let leading_zero = MeshCode::try_from("0001").unwrap();
+        assert_eq!(String::from(leading_zero), "0001");
+
+        assert!(MeshCode::try_from("5339x611").is_err());
+        assert!(MeshCode::try_from(String::from("533988")).is_err());
+    }
+
+    #[test]
+    fn test_step_matches_sequential_neighbors() {
+        use crate::operations::neighbor;
+
+        for code in ["53394611", "5339461142", "5339461197", "533946", "53393599"] {
+            let mesh = MeshCode::from_str(code).unwrap();
+            for dir in Direction::ALL {
+                let mut expected = mesh;
+                for _ in 0..3 {
+                    expected = neighbor(expected, dir).unwrap();
+                }
+                assert_eq!(mesh.step(dir, 3), Some(expected), "{code} {dir:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_step_negative_and_zero() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        assert_eq!(mesh.step(Direction::North, 0), Some(mesh));
+        assert_eq!(
+            mesh.step(Direction::East, -2),
+            mesh.step(Direction::West, 2)
+        );
+        assert_eq!(
+            mesh.step(Direction::NorthEast, -4),
+            mesh.step(Direction::SouthWest, 4)
+        );
+    }
+
+    #[test]
+    fn test_step_out_of_range() {
+        let mesh = MeshCode::from_str("6841").unwrap();
+        assert_eq!(mesh.step(Direction::North, 1), None);
+        assert_eq!(mesh.step(Direction::East, i64::MAX), None);
+        assert!(mesh.step(Direction::South, 1).is_some());
+    }
+
+    #[test]
+    fn test_is_neighbor_of() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let east = MeshCode::from_str("53394612").unwrap();
+        let north = MeshCode::from_str("53394621").unwrap();
+        let north_east = MeshCode::from_str("53394622").unwrap();
+        assert_eq!(mesh.is_neighbor_of(&east), Some(Direction::East));
+        assert_eq!(mesh.is_neighbor_of(&north), Some(Direction::North));
+        assert_eq!(mesh.is_neighbor_of(&north_east), Some(Direction::NorthEast));
+        assert_eq!(north_east.is_neighbor_of(&mesh), Some(Direction::SouthWest));
+
+        // 2次メッシュの境界をまたぐ隣接
+        let left = MeshCode::from_str("53394509").unwrap();
+        let right = MeshCode::from_str("53394600").unwrap();
+        assert_eq!(left.is_neighbor_of(&right), Some(Direction::East));
+
+        let far = MeshCode::from_str("53394613").unwrap();
+        assert_eq!(mesh.is_neighbor_of(&far), None);
+        assert_eq!(mesh.is_neighbor_of(&mesh), None);
+        let other_level = MeshCode::from_str("533946").unwrap();
+        assert_eq!(mesh.is_neighbor_of(&other_level), None);
+    }
+
+    #[test]
+    fn test_is_neighbor_of_matches_neighbor() {
+        let mesh = MeshCode::from_str("5339461142").unwrap();
+        for dir in Direction::ALL {
+            let n = crate::operations::neighbor(mesh, dir).unwrap();
+            assert_eq!(mesh.is_neighbor_of(&n), Some(dir));
+        }
+    }
+
+    #[test]
+    fn test_area_square_meters() {
+        let tokyo = MeshCode::from_str("53394611").unwrap();
+        let hokkaido = MeshCode::from_str("64414277").unwrap();
+        assert!(tokyo.area_square_meters() > hokkaido.area_square_meters());
+
+        // 子メッシュの面積の合計は親メッシュの面積にほぼ等しい
+        let parent = MeshCode::from_str("533946").unwrap();
+        let total: f64 = crate::operations::children(parent)
+            .iter()
+            .map(|m| m.area_square_meters())
+            .sum();
+        assert!((total - parent.area_square_meters()).abs() / total < 1e-4);
+    }
+
+    #[test]
+    fn test_as_grouped_string() {
+        let cases = [
+            ("5339", "5339"),
+            ("533946", "5339-46"),
+            ("53394611", "5339-46-11"),
+            ("533946111", "5339-46-11-1"),
+            ("5339461142", "5339-46-11-4-2"),
+            ("53394611423", "5339-46-11-4-2-3"),
+        ];
+        for (code, grouped) in cases {
+            assert_eq!(
+                MeshCode::from_str(code).unwrap().as_grouped_string(),
+                grouped
+            );
+        }
+
+        let fifth = MeshCode::new(MeshLevel::Fifth, 5339461197).unwrap();
+        assert_eq!(fifth.as_grouped_string(), "5339-46-11-97");
+        let padded = MeshCode::new(MeshLevel::Third, 1000).unwrap();
+        assert_eq!(padded.as_grouped_string(), "0000-10-00");
+    }
+
+    #[test]
+    fn test_disambiguate_10digit() {
+        use crate::convert::coord_to_mesh;
+
+        // 東京駅の5次メッシュ「5339461173」は4分の1メッシュとしては不正（9〜10桁目は1〜4）
+        let hint = Coordinate::new(35.6812, 139.7671).unwrap();
+        let fifth = coord_to_mesh(hint, MeshLevel::Fifth).unwrap();
+        let resolved = MeshCode::disambiguate_10digit(&fifth.as_string(), hint).unwrap();
+        assert_eq!(resolved, fifth);
+
+        // 「5339461144」は両方のレベルで有効。北東端に近い座標は4分の1メッシュにのみ含まれる
+        let quarter = MeshCode::from_str("5339461144").unwrap();
+        let ne = mesh_to_bounds(quarter).north_east();
+        let near_ne = Coordinate::new(ne.lat() - 1e-6, ne.lon() - 1e-6).unwrap();
+        let resolved = MeshCode::disambiguate_10digit("5339461144", near_ne).unwrap();
+        assert_eq!(resolved.level(), MeshLevel::FourthQuarter);
+
+        // 5次メッシュ側にのみ含まれる座標
+        let fifth = MeshCode::new(MeshLevel::Fifth, 5339461144).unwrap();
+        let center = mesh_to_bounds(fifth).center();
+        let resolved = MeshCode::disambiguate_10digit("5339461144", center).unwrap();
+        assert_eq!(resolved.level(), MeshLevel::Fifth);
+    }
+
+    #[test]
+    fn test_disambiguate_10digit_errors() {
+        let far = Coordinate::new(43.0, 141.0).unwrap();
+        assert!(MeshCode::disambiguate_10digit("5339461144", far).is_err());
+        assert!(MeshCode::disambiguate_10digit("53394611", far).is_err());
+
+        // 両方のメッシュに含まれる座標では判定できない
+        let quarter = MeshCode::from_str("5339461111").unwrap();
+        let fifth = MeshCode::new(MeshLevel::Fifth, 5339461111).unwrap();
+        let both = mesh_to_bounds(fifth).center();
+        assert!(mesh_to_bounds(quarter).contains(both));
+        assert!(MeshCode::disambiguate_10digit("5339461111", both).is_err());
+    }
+
+    #[test]
+    fn test_bounding_circle() {
+        use crate::utils::distance::haversine_distance;
+
+        for code in ["5339", "53394611", "5339461142", "64414277"] {
+            let mesh = MeshCode::from_str(code).unwrap();
+            let (center, radius) = mesh.bounding_circle();
+            let bounds = mesh_to_bounds(mesh);
+
+            let height = haversine_distance(
+                Coordinate::new_unchecked(bounds.min_lat(), center.lon()),
+                Coordinate::new_unchecked(bounds.max_lat(), center.lon()),
+            );
+            let width = haversine_distance(
+                Coordinate::new_unchecked(center.lat(), bounds.min_lon()),
+                Coordinate::new_unchecked(center.lat(), bounds.max_lon()),
+            );
+            assert!(radius > height.min(width) / 2.0, "{code}");
+
+            for (lat, lon) in [
+                (bounds.min_lat(), bounds.min_lon()),
+                (bounds.min_lat(), bounds.max_lon()),
+                (bounds.max_lat(), bounds.min_lon()),
+                (bounds.max_lat(), bounds.max_lon()),
+            ] {
+                let corner = Coordinate::new_unchecked(lat, lon);
+                assert!(haversine_distance(center, corner) <= radius, "{code}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_labeled_display() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        assert_eq!(mesh.labeled().to_string(), "[3]53394611");
+        assert_eq!(format!("{}", mesh.labeled()), "[3]53394611");
+
+        let first = MeshCode::from_str("5339").unwrap();
+        assert_eq!(first.labeled().to_string(), "[1]5339");
+
+        let fifth = MeshCode::from_str("5339461197").unwrap();
+        assert_eq!(fifth.labeled().to_string(), "[7]5339461197");
+
+        // 通常のDisplayはレベル番号を含まない
+        assert_eq!(mesh.to_string(), "53394611");
+    }
+
+    #[test]
+    fn test_child_of_third() {
+        let third = MeshCode::from_str("53394611").unwrap();
+        let bounds = mesh_to_bounds(third);
+        let center = bounds.center();
+
+        // 分割番号4は北東の2分の1メッシュ
+        let ne = third.child(4).unwrap();
+        assert_eq!(ne.level(), MeshLevel::FourthHalf);
+        assert_eq!(ne.as_string(), "533946114");
+        let ne_bounds = mesh_to_bounds(ne);
+        assert!((ne_bounds.min_lat() - center.lat()).abs() < 1e-9);
+        assert!((ne_bounds.min_lon() - center.lon()).abs() < 1e-9);
+
+        // 分割番号1は南西の2分の1メッシュ
+        let sw = third.child(1).unwrap();
+        assert_eq!(sw.as_string(), "533946111");
+        assert!((mesh_to_bounds(sw).max_lat() - center.lat()).abs() < 1e-9);
+
+        assert_eq!(third.child(0), Err(MeshCodeError::OutOfRange));
+        assert_eq!(third.child(5), Err(MeshCodeError::OutOfRange));
+    }
+
+    #[test]
+    fn test_child_at_level() {
+        let third = MeshCode::from_str("53394611").unwrap();
+        let half = third.child(2).unwrap();
+
+        assert_eq!(
+            half.child(3).unwrap(),
+            half.child_at_level(3, MeshLevel::FourthQuarter).unwrap()
+        );
+        let eighth = half.child(3).unwrap().child(4).unwrap();
+        assert_eq!(eighth.as_string(), "53394611234");
+        assert!(matches!(
+            eighth.child(1),
+            Err(MeshCodeError::InvalidFormat(_))
+        ));
+
+        assert_eq!(
+            third
+                .child_at_level(0, MeshLevel::Fifth)
+                .unwrap()
+                .as_string(),
+            "5339461100"
+        );
+        assert_eq!(
+            third.child_at_level(100, MeshLevel::Fifth),
+            Err(MeshCodeError::OutOfRange)
+        );
+        assert!(matches!(
+            third.child_at_level(1, MeshLevel::FourthQuarter),
+            Err(MeshCodeError::InvalidFormat(_))
+        ));
+
+        let first = MeshCode::from_str("5339").unwrap();
+        assert_eq!(
+            first
+                .child_at_level(46, MeshLevel::Second)
+                .unwrap()
+                .as_string(),
+            "533946"
+        );
+        assert_eq!(
+            first.child_at_level(48, MeshLevel::Second),
+            Err(MeshCodeError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_shares_parent() {
+        let a = MeshCode::from_str("53394611").unwrap();
+        let b = MeshCode::from_str("53394699").unwrap();
+        let other_second = MeshCode::from_str("53394500").unwrap();
+
+        assert!(a.shares_parent(&b, MeshLevel::Second));
+        assert!(a.shares_parent(&b, MeshLevel::First));
+        assert!(!a.shares_parent(&b, MeshLevel::Third));
+        assert!(a.shares_parent(&a, MeshLevel::Third));
+        assert!(!a.shares_parent(&other_second, MeshLevel::Second));
+        assert!(a.shares_parent(&other_second, MeshLevel::First));
+
+        // 異なるレベル同士でも祖先で比較できる
+        let half = MeshCode::from_str("533946993").unwrap();
+        assert!(half.shares_parent(&a, MeshLevel::Second));
+
+        // at_levelより粗いメッシュはfalse
+        let second = MeshCode::from_str("533946").unwrap();
+        assert!(!second.shares_parent(&a, MeshLevel::Third));
+        assert!(!a.shares_parent(&second, MeshLevel::Third));
+
+        // 祖先でないレベルはfalse
+        let fifth = MeshCode::parse("5339461197", Some(MeshLevel::Fifth)).unwrap();
+        assert!(!fifth.shares_parent(&fifth, MeshLevel::FourthHalf));
+    }
+
+    #[test]
+    fn test_intersects_bbox() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let bounds = mesh_to_bounds(mesh);
+
+        // 南西側の一部だけが重なる
+        let partial = BoundingBox::new(
+            Coordinate::new(bounds.min_lat() - 0.01, bounds.min_lon() - 0.01).unwrap(),
+            Coordinate::new(bounds.center().lat(), bounds.center().lon()).unwrap(),
+        );
+        assert!(mesh.intersects_bbox(&partial));
+
+        let disjoint = BoundingBox::new(
+            Coordinate::new(36.0, 140.0).unwrap(),
+            Coordinate::new(36.1, 140.1).unwrap(),
+        );
+        assert!(!mesh.intersects_bbox(&disjoint));
+
+        // 東側の辺で接するだけのボックスは重ならない
+        let touching = BoundingBox::new(
+            Coordinate::new(bounds.min_lat(), bounds.max_lon()).unwrap(),
+            Coordinate::new(bounds.max_lat(), bounds.max_lon() + 0.01).unwrap(),
+        );
+        assert!(!mesh.intersects_bbox(&touching));
+    }
+
+    #[test]
+    fn test_canonical11_roundtrip() {
+        let codes = [
+            ("5339", MeshLevel::First),
+            ("533946", MeshLevel::Second),
+            ("53394611", MeshLevel::Third),
+            ("533946113", MeshLevel::FourthHalf),
+            ("5339461134", MeshLevel::FourthQuarter),
+            ("53394611342", MeshLevel::FourthEighth),
+            ("5339461134", MeshLevel::Fifth),
+            ("0001", MeshLevel::First),
+        ];
+        for (code, level) in codes {
+            let mesh = MeshCode::parse(code, Some(level)).unwrap();
+            let canonical = mesh.to_canonical11();
+            assert_eq!(canonical.len(), 12);
+            assert_eq!(MeshCode::from_canonical11(&canonical).unwrap(), mesh);
+        }
+
+        assert!(MeshCode::from_canonical11("353394611001").is_err());
+        assert!(MeshCode::from_canonical11("853394611000").is_err());
+        assert!(MeshCode::from_canonical11("3533946110").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_with_str_hashmap_lookup() {
+        use std::collections::HashMap;
+
+        let mut populations: HashMap<String, i32> = HashMap::new();
+        populations.insert("53394611".to_string(), 120);
+        populations.insert("0001".to_string(), 3);
+
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        assert_eq!(mesh.with_str(|s| populations.get(s).copied()), Some(120));
+
+        let padded = MeshCode::from_str("0001").unwrap();
+        assert_eq!(padded.with_str(|s| populations.get(s).copied()), Some(3));
+
+        let missing = MeshCode::from_str("53394612").unwrap();
+        assert_eq!(missing.with_str(|s| populations.get(s).copied()), None);
+
+        let eighth = MeshCode::from_str("53394611342").unwrap();
+        assert!(eighth.with_str(|s| s == eighth.as_string()));
+    }
+
+    #[test]
+    fn test_corner_tuples() {
+        for code in ["5339", "53394611", "533946113", "0001"] {
+            let mesh = MeshCode::from_str(code).unwrap();
+            let bounds = mesh_to_bounds(mesh);
+            let [sw, se, ne, nw] = mesh.corner_tuples();
+            assert_eq!(sw, (bounds.south_west().lat(), bounds.south_west().lon()));
+            assert_eq!(se, (bounds.min_lat(), bounds.max_lon()));
+            assert_eq!(ne, (bounds.north_east().lat(), bounds.north_east().lon()));
+            assert_eq!(nw, (bounds.max_lat(), bounds.min_lon()));
+        }
+    }
+
     #[test]
     fn test_mesh_code_display() {
         let mesh = MeshCode::from_str("0001").unwrap();
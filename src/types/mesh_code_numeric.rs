@@ -0,0 +1,131 @@
+use crate::error::{MeshCodeError, Result};
+use crate::types::mesh_code::MeshCode;
+use alloc::string::ToString;
+use core::ops::Deref;
+
+/// 数値としてシリアライズされるメッシュコード
+///
+/// [`MeshCode`]は`serde`で文字列（例: `"53394611"`）としてシリアライズされますが、
+/// この型は桁の並びをそのまま数値（例: `53394611`）として扱います。
+/// メッシュコードを整数でJSONに格納しているデータとのやり取りに使用します。
+///
+/// 数値では先頭のゼロを表現できないため、1次メッシュコードが`0`で始まる
+/// メッシュ（例: `"0001"`）は数値に変換できず、[`MeshCodeNumeric::to_u64`]や
+/// シリアライズはエラーになります。デシリアライズ時は数値の桁数からレベルを
+/// 判定するため、先頭のゼロが失われた値は別のレベルとして解釈されるか
+/// エラーになります。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCodeNumeric::from(MeshCode::from_str("53394611").unwrap());
+/// assert_eq!(mesh.to_u64().unwrap(), 53394611);
+/// assert_eq!(MeshCodeNumeric::try_from(53394611u64).unwrap(), mesh);
+///
+/// let leading_zero = MeshCodeNumeric::from(MeshCode::from_str("0001").unwrap());
+/// assert!(leading_zero.to_u64().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MeshCodeNumeric(pub MeshCode);
+
+impl MeshCodeNumeric {
+    /// 元のメッシュコードを返す
+    pub fn mesh(&self) -> MeshCode {
+        self.0
+    }
+
+    /// 桁の並びを表す数値を返す
+    ///
+    /// 1次メッシュコードが`0`で始まる場合は、数値から元のコードに戻せないため
+    /// [`MeshCodeError::InvalidFormat`]を返します。
+    pub fn to_u64(&self) -> Result<u64> {
+        let code = self.0.code();
+        let len = self.0.level().code_length() as u32;
+        if code < 10u64.pow(len - 1) {
+            return Err(MeshCodeError::InvalidFormat(alloc::format!(
+                "mesh code {} has a leading zero and cannot be represented as a number",
+                self.0
+            )));
+        }
+        Ok(code)
+    }
+}
+
+impl From<MeshCode> for MeshCodeNumeric {
+    fn from(mesh: MeshCode) -> Self {
+        MeshCodeNumeric(mesh)
+    }
+}
+
+impl From<MeshCodeNumeric> for MeshCode {
+    fn from(mesh: MeshCodeNumeric) -> Self {
+        mesh.0
+    }
+}
+
+impl TryFrom<u64> for MeshCodeNumeric {
+    type Error = MeshCodeError;
+
+    /// 数値の桁数からレベルを判定してメッシュコードを作成する
+    fn try_from(value: u64) -> Result<Self> {
+        MeshCode::from_str(&value.to_string()).map(MeshCodeNumeric)
+    }
+}
+
+impl Deref for MeshCodeNumeric {
+    type Target = MeshCode;
+
+    fn deref(&self) -> &MeshCode {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MeshCodeNumeric {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        let value = self.to_u64().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_u64(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MeshCodeNumeric {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        MeshCodeNumeric::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_u64_and_back() {
+        let mesh = MeshCode::from_str("533946113").unwrap();
+        let numeric = MeshCodeNumeric::from(mesh);
+        assert_eq!(numeric.to_u64().unwrap(), 533946113);
+        assert_eq!(
+            MeshCodeNumeric::try_from(533946113u64).unwrap().mesh(),
+            mesh
+        );
+    }
+
+    #[test]
+    fn test_leading_zero_is_rejected() {
+        let mesh = MeshCode::from_str("05394611").unwrap();
+        assert!(matches!(
+            MeshCodeNumeric::from(mesh).to_u64(),
+            Err(MeshCodeError::InvalidFormat(_))
+        ));
+        // 先頭のゼロが失われた7桁の数値は有効なレベルにならない
+        assert!(MeshCodeNumeric::try_from(5394611u64).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::types::mesh_code::MeshCode;
+use core::fmt;
+use core::ops::Deref;
+
+/// 文字列表現をあらかじめ保持するメッシュコード
+///
+/// [`MeshCode`]の`Display`や[`MeshCode::as_string`]は呼び出しのたびに
+/// 文字列を生成します。ログ出力やシリアライズで同じメッシュコードを
+/// 何度も文字列化する場合は、この型で一度だけ整形しておくと、以降の
+/// `Display`や[`MeshCodeStr::as_str`]はヒープ確保なしで行えます。
+///
+/// 文字列は最大11桁のため、ヒープではなく固定長のバッファに格納します。
+/// `Deref<Target = MeshCode>`を実装しているため、[`MeshCode`]のメソッドを
+/// そのまま呼び出せます。
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCodeStr::new(MeshCode::from_str("53394611").unwrap());
+/// assert_eq!(mesh.as_str(), "53394611");
+/// assert_eq!(mesh.level(), MeshLevel::Third);
+/// assert_eq!(format!("{mesh}"), "53394611");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MeshCodeStr {
+    mesh: MeshCode,
+    digits: [u8; 11],
+    len: u8,
+}
+
+impl MeshCodeStr {
+    /// メッシュコードを整形して新しい`MeshCodeStr`を作成する
+    pub fn new(mesh: MeshCode) -> Self {
+        let len = mesh.level().code_length();
+        let mut digits = [0u8; 11];
+        let mut code = mesh.code();
+        for digit in digits[..len].iter_mut().rev() {
+            *digit = b'0' + (code % 10) as u8;
+            code /= 10;
+        }
+        MeshCodeStr {
+            mesh,
+            digits,
+            len: len as u8,
+        }
+    }
+
+    /// 元のメッシュコードを返す
+    pub fn mesh(&self) -> MeshCode {
+        self.mesh
+    }
+
+    /// 保持している文字列表現を返す
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.digits[..self.len as usize])
+            .expect("mesh code digits are always ASCII")
+    }
+}
+
+impl From<MeshCode> for MeshCodeStr {
+    fn from(mesh: MeshCode) -> Self {
+        MeshCodeStr::new(mesh)
+    }
+}
+
+impl From<MeshCodeStr> for MeshCode {
+    fn from(mesh: MeshCodeStr) -> Self {
+        mesh.mesh
+    }
+}
+
+impl Deref for MeshCodeStr {
+    type Target = MeshCode;
+
+    fn deref(&self) -> &MeshCode {
+        &self.mesh
+    }
+}
+
+impl AsRef<str> for MeshCodeStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for MeshCodeStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeshCodeStr")
+            .field("level", &self.mesh.level())
+            .field("code", &self.as_str())
+            .finish()
+    }
+}
+
+impl fmt::Display for MeshCodeStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_matches_as_string() {
+        for code in [
+            "0001",
+            "5339",
+            "533946",
+            "53394611",
+            "533946112",
+            "53394611234",
+        ] {
+            let mesh = MeshCode::from_str(code).unwrap();
+            let interned = MeshCodeStr::new(mesh);
+            assert_eq!(interned.as_str(), mesh.as_string());
+            assert_eq!(format!("{interned}"), code);
+            assert_eq!(*interned, mesh);
+        }
+    }
+}
@@ -1,4 +1,8 @@
 use crate::error::{MeshCodeError, Result};
+use crate::utils::distance::EARTH_RADIUS_METERS;
+use crate::utils::math;
+use alloc::format;
+use core::fmt;
 
 /// メッシュのレベル（次数）を表す列挙型
 ///
@@ -24,6 +28,17 @@ pub enum MeshLevel {
     Fifth = 7,
 }
 
+/// サイズの大きい順に並べたメッシュレベル
+const BY_SIZE: [MeshLevel; 7] = [
+    MeshLevel::First,
+    MeshLevel::Second,
+    MeshLevel::Third,
+    MeshLevel::FourthHalf,
+    MeshLevel::FourthQuarter,
+    MeshLevel::FourthEighth,
+    MeshLevel::Fifth,
+];
+
 impl MeshLevel {
     /// メッシュコードの桁数からメッシュレベルを判定する
     ///
@@ -32,6 +47,10 @@ impl MeshLevel {
     ///
     /// # 戻り値
     /// 対応するメッシュレベル、または無効な桁数の場合はエラー
+    ///
+    /// 4〜11桁の範囲内でメッシュレベルが存在しない5桁・7桁は、入力ミスの
+    /// 可能性が高いため、有効な桁数（4/6/8/9/10/11）を示す
+    /// [`MeshCodeError::InvalidFormat`]を返します。
     pub fn from_code_length(len: usize) -> Result<Self> {
         match len {
             4 => Ok(MeshLevel::First),
@@ -40,6 +59,9 @@ impl MeshLevel {
             9 => Ok(MeshLevel::FourthHalf),
             10 => Ok(MeshLevel::FourthQuarter),
             11 => Ok(MeshLevel::FourthEighth),
+            5 | 7 => Err(MeshCodeError::InvalidFormat(format!(
+                "no mesh level has {len} digits (valid lengths are 4, 6, 8, 9, 10 or 11)"
+            ))),
             _ => Err(MeshCodeError::InvalidLevel(len)),
         }
     }
@@ -76,7 +98,18 @@ impl MeshLevel {
     }
 
     /// このメッシュレベルのコード桁数を返す
-    pub fn code_length(self) -> usize {
+    ///
+    /// `const fn`のため、配列の長さなど定数式の中でも使用できます。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let buf = [0u8; MeshLevel::FourthEighth.code_length()];
+    /// assert_eq!(buf.len(), 11);
+    /// ```
+    pub const fn code_length(self) -> usize {
         match self {
             MeshLevel::First => 4,
             MeshLevel::Second => 6,
@@ -89,7 +122,7 @@ impl MeshLevel {
     }
 
     /// このメッシュレベルの緯度方向のサイズを度数で返す
-    pub fn lat_size_degrees(self) -> f64 {
+    pub const fn lat_size_degrees(self) -> f64 {
         match self {
             MeshLevel::First => 40.0 / 60.0,
             MeshLevel::Second => 5.0 / 60.0,
@@ -102,7 +135,7 @@ impl MeshLevel {
     }
 
     /// このメッシュレベルの経度方向のサイズを度数で返す
-    pub fn lon_size_degrees(self) -> f64 {
+    pub const fn lon_size_degrees(self) -> f64 {
         match self {
             MeshLevel::First => 1.0,
             MeshLevel::Second => 7.5 / 60.0,
@@ -127,6 +160,77 @@ impl MeshLevel {
         }
     }
 
+    /// 指定した緯度でのメッシュの南北・東西の長さをメートルで返す
+    ///
+    /// [`MeshLevel::approximate_size_meters`]はレベルごとの固定値ですが、東西方向の
+    /// 長さは緯度が高くなるほど短くなります。緯度・経度方向の度数サイズを地球半径で
+    /// メートルに換算し、東西方向には`lat`の余弦を掛けて補正します。
+    ///
+    /// # 引数
+    /// * `lat` - 緯度（度）
+    ///
+    /// # 戻り値
+    /// `(南北の長さ, 東西の長さ)`（メートル）
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// let (height, width) = MeshLevel::Third.size_meters_at(35.0);
+    /// assert!((height - 926.6).abs() < 1.0);
+    /// assert!((width - 1138.7).abs() < 1.0);
+    /// ```
+    pub fn size_meters_at(self, lat: f64) -> (f64, f64) {
+        let height = self.lat_size_degrees().to_radians() * EARTH_RADIUS_METERS;
+        let width = self.lon_size_degrees().to_radians()
+            * EARTH_RADIUS_METERS
+            * math::cos(lat.to_radians());
+        (height, width)
+    }
+
+    /// おおよそのサイズが`meters`以上のレベルのうち、最も細かいレベルを返す
+    ///
+    /// 表示範囲に応じて「少なくともNメートル四方」のメッシュを選ぶ用途を想定しています。
+    /// [`MeshLevel::approximate_size_meters`]で比較し、該当するレベルがない
+    /// （1次メッシュより大きい）場合は[`MeshLevel::First`]を返します。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// assert_eq!(MeshLevel::coarsest_at_least(900.0), MeshLevel::Third);
+    /// ```
+    pub fn coarsest_at_least(meters: f64) -> MeshLevel {
+        BY_SIZE
+            .iter()
+            .rev()
+            .copied()
+            .find(|level| level.approximate_size_meters() >= meters)
+            .unwrap_or(MeshLevel::First)
+    }
+
+    /// おおよそのサイズが`meters`以下のレベルのうち、最も粗いレベルを返す
+    ///
+    /// [`MeshLevel::approximate_size_meters`]で比較し、該当するレベルがない
+    /// （5次メッシュより小さい）場合は[`MeshLevel::Fifth`]を返します。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// assert_eq!(MeshLevel::finest_at_most(300.0), MeshLevel::FourthQuarter);
+    /// ```
+    pub fn finest_at_most(meters: f64) -> MeshLevel {
+        BY_SIZE
+            .iter()
+            .copied()
+            .find(|level| level.approximate_size_meters() <= meters)
+            .unwrap_or(MeshLevel::Fifth)
+    }
+
     /// このメッシュレベルの親レベルを返す（1次メッシュの場合はNone）
     ///
     /// 分割地域メッシュは段階的に分割されるため、4分の1の親は2分の1、
@@ -143,6 +247,66 @@ impl MeshLevel {
         }
     }
 
+    /// 粗いレベルのメッシュ1つに含まれる細かいレベルのメッシュの数を返す
+    ///
+    /// 例えば1次→2次は64、2次→3次は100、3次→5次は100、3次→8分の1は64です。
+    /// 同じレベルの場合は1を返します。`finer`が`coarser`の子孫レベル
+    /// （[`MeshLevel::parent`]をたどって`coarser`に到達できるレベル）でない場合は
+    /// Noneを返します。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// assert_eq!(MeshLevel::subdivision_count(MeshLevel::First, MeshLevel::Third), Some(6400));
+    /// assert_eq!(MeshLevel::subdivision_count(MeshLevel::Fifth, MeshLevel::FourthHalf), None);
+    /// ```
+    pub fn subdivision_count(coarser: MeshLevel, finer: MeshLevel) -> Option<u64> {
+        let mut count = 1;
+        let mut level = finer;
+        while level != coarser {
+            count *= level.split_count();
+            level = level.parent()?;
+        }
+        Some(count)
+    }
+
+    /// 親レベルのメッシュ1つがこのレベルのメッシュいくつに分割されるかを返す
+    fn split_count(self) -> u64 {
+        match self {
+            MeshLevel::First => 1,
+            MeshLevel::Second => 64,
+            MeshLevel::Third => 100,
+            MeshLevel::FourthHalf | MeshLevel::FourthQuarter | MeshLevel::FourthEighth => 4,
+            MeshLevel::Fifth => 100,
+        }
+    }
+
+    /// メッシュレベルの日本語名を返す
+    ///
+    /// `Display`もこの名称を出力します。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use jismeshcode::prelude::*;
+    ///
+    /// assert_eq!(MeshLevel::Third.label(), "3次メッシュ");
+    /// assert_eq!(MeshLevel::FourthHalf.to_string(), "4次メッシュ（2分の1）");
+    /// ```
+    pub fn label(self) -> &'static str {
+        match self {
+            MeshLevel::First => "1次メッシュ",
+            MeshLevel::Second => "2次メッシュ",
+            MeshLevel::Third => "3次メッシュ",
+            MeshLevel::FourthHalf => "4次メッシュ（2分の1）",
+            MeshLevel::FourthQuarter => "4次メッシュ（4分の1）",
+            MeshLevel::FourthEighth => "4次メッシュ（8分の1）",
+            MeshLevel::Fifth => "5次メッシュ",
+        }
+    }
+
     pub fn as_u8(self) -> u8 {
         self as u8
     }
@@ -161,10 +325,57 @@ impl MeshLevel {
     }
 }
 
+impl fmt::Display for MeshLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_size_meters_at() {
+        for level in BY_SIZE {
+            let (height_25, width_25) = level.size_meters_at(25.0);
+            let (height_45, width_45) = level.size_meters_at(45.0);
+            assert_eq!(height_25, height_45);
+            assert!(width_45 < width_25);
+        }
+
+        // 3次メッシュの東西の長さは北緯35度で約1.14km
+        let (_, width) = MeshLevel::Third.size_meters_at(35.0);
+        assert!((width - 1138.7).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_const_sizes() {
+        const THIRD_LEN: usize = MeshLevel::Third.code_length();
+        const HALF_LAT: f64 = MeshLevel::FourthHalf.lat_size_degrees();
+        const HALF_LON: f64 = MeshLevel::FourthHalf.lon_size_degrees();
+
+        let buf = [0u8; MeshLevel::FourthEighth.code_length()];
+        assert_eq!(buf.len(), 11);
+        assert_eq!(THIRD_LEN, 8);
+        assert_eq!(HALF_LAT, MeshLevel::Third.lat_size_degrees() / 2.0);
+        assert_eq!(HALF_LON, MeshLevel::Third.lon_size_degrees() / 2.0);
+    }
+
+    #[test]
+    fn test_coarsest_at_least_and_finest_at_most() {
+        assert_eq!(MeshLevel::coarsest_at_least(900.0), MeshLevel::Third);
+        assert_eq!(MeshLevel::coarsest_at_least(1000.0), MeshLevel::Third);
+        assert_eq!(MeshLevel::coarsest_at_least(110.0), MeshLevel::FourthEighth);
+        assert_eq!(MeshLevel::coarsest_at_least(50.0), MeshLevel::Fifth);
+        assert_eq!(MeshLevel::coarsest_at_least(100000.0), MeshLevel::First);
+
+        assert_eq!(MeshLevel::finest_at_most(300.0), MeshLevel::FourthQuarter);
+        assert_eq!(MeshLevel::finest_at_most(10000.0), MeshLevel::Second);
+        assert_eq!(MeshLevel::finest_at_most(100000.0), MeshLevel::First);
+        assert_eq!(MeshLevel::finest_at_most(50.0), MeshLevel::Fifth);
+    }
+
     #[test]
     fn test_from_code_length() {
         assert_eq!(MeshLevel::from_code_length(4).unwrap(), MeshLevel::First);
@@ -173,6 +384,15 @@ mod tests {
         assert!(MeshLevel::from_code_length(3).is_err());
     }
 
+    #[test]
+    fn test_from_code_length_nonexistent_levels() {
+        for len in [5, 7] {
+            let message = MeshLevel::from_code_length(len).unwrap_err().to_string();
+            assert!(message.contains(&format!("{len} digits")), "{message}");
+            assert!(message.contains("4, 6, 8, 9, 10 or 11"), "{message}");
+        }
+    }
+
     #[test]
     fn test_lat_lon_size() {
         assert!((MeshLevel::First.lat_size_degrees() - 40.0 / 60.0).abs() < 1e-10);
@@ -180,6 +400,54 @@ mod tests {
         assert!((MeshLevel::Third.lat_size_degrees() - 30.0 / 3600.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_display() {
+        let expected = [
+            (MeshLevel::First, "1次メッシュ"),
+            (MeshLevel::Second, "2次メッシュ"),
+            (MeshLevel::Third, "3次メッシュ"),
+            (MeshLevel::FourthHalf, "4次メッシュ（2分の1）"),
+            (MeshLevel::FourthQuarter, "4次メッシュ（4分の1）"),
+            (MeshLevel::FourthEighth, "4次メッシュ（8分の1）"),
+            (MeshLevel::Fifth, "5次メッシュ"),
+        ];
+        for (level, label) in expected {
+            assert_eq!(format!("{level}"), label);
+            assert_eq!(level.label(), label);
+        }
+    }
+
+    #[test]
+    fn test_subdivision_count() {
+        use MeshLevel::*;
+        let cases = [
+            (First, Second, 64),
+            (Second, Third, 100),
+            (First, Third, 6400),
+            (Third, Fifth, 100),
+            (Third, FourthHalf, 4),
+            (Third, FourthQuarter, 16),
+            (Third, FourthEighth, 64),
+            (FourthHalf, FourthQuarter, 4),
+            (FourthHalf, FourthEighth, 16),
+            (FourthQuarter, FourthEighth, 4),
+            (Second, Fifth, 10000),
+            (Third, Third, 1),
+        ];
+        for (coarser, finer, expected) in cases {
+            assert_eq!(
+                MeshLevel::subdivision_count(coarser, finer),
+                Some(expected),
+                "{coarser:?} -> {finer:?}"
+            );
+        }
+
+        // 子孫レベルでない組み合わせ
+        assert_eq!(MeshLevel::subdivision_count(Second, First), None);
+        assert_eq!(MeshLevel::subdivision_count(FourthHalf, Fifth), None);
+        assert_eq!(MeshLevel::subdivision_count(Fifth, FourthEighth), None);
+    }
+
     #[test]
     fn test_parent() {
         assert_eq!(MeshLevel::Third.parent(), Some(MeshLevel::Second));
@@ -1,11 +1,19 @@
 mod bounding_box;
 mod coordinate;
 mod direction;
+mod fourth_mesh;
+mod grid;
 mod mesh_code;
+mod mesh_code_numeric;
+mod mesh_code_str;
 mod mesh_level;
 
-pub use bounding_box::BoundingBox;
+pub(crate) use bounding_box::JAPAN_BOUNDS;
+pub use bounding_box::{japan_mesh_bounds, BoundingBox};
 pub use coordinate::Coordinate;
 pub use direction::Direction;
-pub use mesh_code::MeshCode;
+pub use fourth_mesh::FourthMesh;
+pub use mesh_code::{LabeledMeshCode, MeshCode};
+pub use mesh_code_numeric::MeshCodeNumeric;
+pub use mesh_code_str::MeshCodeStr;
 pub use mesh_level::MeshLevel;
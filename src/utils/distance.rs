@@ -1,8 +1,9 @@
-use crate::types::Coordinate;
+use crate::convert::{mesh_to_bounds, mesh_to_center};
+use crate::types::{Coordinate, MeshCode, JAPAN_BOUNDS};
 use crate::utils::math;
 
 /// 地球の半径（メートル）
-const EARTH_RADIUS_METERS: f64 = 6371000.0;
+pub(crate) const EARTH_RADIUS_METERS: f64 = 6371000.0;
 
 /// 2点間の距離をHaversine公式で計算する
 ///
@@ -27,10 +28,102 @@ const EARTH_RADIUS_METERS: f64 = 6371000.0;
 /// println!("東京-横浜間の距離: {:.2}km", distance / 1000.0);
 /// ```
 pub fn haversine_distance(coord1: Coordinate, coord2: Coordinate) -> f64 {
-    let lat1 = coord1.lat().to_radians();
-    let lat2 = coord2.lat().to_radians();
-    let lon1 = coord1.lon().to_radians();
-    let lon2 = coord2.lon().to_radians();
+    haversine_distance_with_radius(coord1, coord2, EARTH_RADIUS_METERS)
+}
+
+/// 地球の半径を指定して2点間の距離をHaversine公式で計算する
+///
+/// [`haversine_distance`]は地球の半径を6,371,000メートルとして計算します。
+/// GRS80楕円体の平均半径（6,371,008.8メートル）など、他のツールと基準を
+/// 揃える場合に使用します。
+///
+/// # 引数
+/// * `coord1` - 1つ目の座標
+/// * `coord2` - 2つ目の座標
+/// * `radius_m` - 地球の半径（メートル）
+///
+/// # 戻り値
+/// 2点間の距離（メートル単位）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let yokohama = Coordinate::new(35.4437, 139.6380).unwrap();
+/// let grs80 = haversine_distance_with_radius(tokyo, yokohama, 6_371_008.8);
+/// assert!(grs80 > haversine_distance(tokyo, yokohama));
+/// ```
+pub fn haversine_distance_with_radius(
+    coord1: Coordinate,
+    coord2: Coordinate,
+    radius_m: f64,
+) -> f64 {
+    radius_m * central_angle(coord1, haversine_precompute(coord1), coord2)
+}
+
+/// [`haversine_distance_precomputed`]に渡す固定点の値を計算する
+///
+/// # 戻り値
+/// （緯度のラジアン、緯度の余弦）のタプル
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let (lat_rad, cos_lat) = haversine_precompute(tokyo);
+/// assert_eq!(lat_rad, tokyo.lat_rad());
+/// assert_eq!(cos_lat, tokyo.lat_rad().cos());
+/// ```
+pub fn haversine_precompute(coord: Coordinate) -> (f64, f64) {
+    let lat_rad = coord.lat_rad();
+    (lat_rad, math::cos(lat_rad))
+}
+
+/// 固定点の三角関数を事前計算してHaversine距離を計算する
+///
+/// 1つの固定点と多数の候補点との距離を求める場合に、固定点の緯度のラジアン変換と
+/// 余弦の計算を候補ごとに繰り返さずに済みます。結果は[`haversine_distance`]と
+/// 完全に一致します。
+///
+/// # 引数
+/// * `fixed` - 固定点の座標
+/// * `fixed_trig` - [`haversine_precompute`]で求めた固定点の（緯度のラジアン、緯度の余弦）
+/// * `other` - 候補点の座標
+///
+/// # 戻り値
+/// 2点間の距離（メートル単位）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let trig = haversine_precompute(tokyo);
+/// let yokohama = Coordinate::new(35.4437, 139.6380).unwrap();
+/// assert_eq!(
+///     haversine_distance_precomputed(tokyo, trig, yokohama),
+///     haversine_distance(tokyo, yokohama)
+/// );
+/// ```
+pub fn haversine_distance_precomputed(
+    fixed: Coordinate,
+    fixed_trig: (f64, f64),
+    other: Coordinate,
+) -> f64 {
+    EARTH_RADIUS_METERS * central_angle(fixed, fixed_trig, other)
+}
+
+/// 2点間の中心角（ラジアン）をHaversine公式で計算する
+fn central_angle(fixed: Coordinate, fixed_trig: (f64, f64), other: Coordinate) -> f64 {
+    let (lat1, cos_lat1) = fixed_trig;
+    let lat2 = other.lat_rad();
+    let lon1 = fixed.lon_rad();
+    let lon2 = other.lon_rad();
 
     let dlat = lat2 - lat1;
     let dlon = lon2 - lon1;
@@ -38,10 +131,84 @@ pub fn haversine_distance(coord1: Coordinate, coord2: Coordinate) -> f64 {
     // Haversine公式
     let sin_dlat = math::sin(dlat / 2.0);
     let sin_dlon = math::sin(dlon / 2.0);
-    let a = sin_dlat * sin_dlat + math::cos(lat1) * math::cos(lat2) * sin_dlon * sin_dlon;
-    let c = 2.0 * math::atan2(math::sqrt(a), math::sqrt(1.0 - a));
+    let a = sin_dlat * sin_dlat + cos_lat1 * math::cos(lat2) * sin_dlon * sin_dlon;
+    2.0 * math::atan2(math::sqrt(a), math::sqrt(1.0 - a))
+}
+
+/// 座標からメッシュの最も近い境界までの距離を計算する
+///
+/// 座標がメッシュ内（境界上を含む）にある場合は0.0を返します。
+/// メッシュ外の場合は、緯度・経度をメッシュの範囲にクランプして得られる
+/// メッシュ矩形上の最近点までのHaversine距離を返します。
+///
+/// # 引数
+/// * `coord` - 対象の座標
+/// * `mesh` - 対象のメッシュコード
+///
+/// # 戻り値
+/// メッシュ境界までの距離（メートル単位）
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let inside = mesh_to_center(mesh);
+/// assert_eq!(distance_to_mesh_edge(inside, mesh), 0.0);
+/// ```
+pub fn distance_to_mesh_edge(coord: Coordinate, mesh: MeshCode) -> f64 {
+    let bounds = mesh_to_bounds(mesh);
+    if bounds.contains(coord) {
+        return 0.0;
+    }
+
+    let nearest_lat = coord.lat().clamp(bounds.min_lat(), bounds.max_lat());
+    let nearest_lon = coord.lon().clamp(bounds.min_lon(), bounds.max_lon());
+    let nearest = Coordinate::new_unchecked(nearest_lat, nearest_lon);
+
+    haversine_distance(coord, nearest)
+}
+
+/// メッシュの中心から別のメッシュの中心への方位角を計算する
+///
+/// 2つのメッシュの中心座標を結ぶ大円の、出発点における方位角（初期方位）を返します。
+///
+/// # 引数
+/// * `from` - 出発点のメッシュコード
+/// * `to` - 目的地のメッシュコード
+///
+/// # 戻り値
+/// 北を0度とし時計回りに測った方位角（0.0以上360.0未満、度単位）。
+/// 同じメッシュの場合は0.0
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let mesh = MeshCode::from_str("53394611").unwrap();
+/// let east = neighbor(mesh, Direction::East).unwrap();
+/// let bearing = mesh_bearing(mesh, east);
+/// assert!((bearing - 90.0).abs() < 0.1);
+/// ```
+pub fn mesh_bearing(from: MeshCode, to: MeshCode) -> f64 {
+    let from = mesh_to_center(from);
+    let to = mesh_to_center(to);
+
+    let lat1 = from.lat().to_radians();
+    let lat2 = to.lat().to_radians();
+    let dlon = (to.lon() - from.lon()).to_radians();
+
+    let y = math::sin(dlon) * math::cos(lat2);
+    let x = math::cos(lat1) * math::sin(lat2) - math::sin(lat1) * math::cos(lat2) * math::cos(dlon);
+    let bearing = math::atan2(y, x).to_degrees();
 
-    EARTH_RADIUS_METERS * c
+    if bearing < 0.0 {
+        bearing + 360.0
+    } else {
+        bearing
+    }
 }
 
 /// 指定距離に対応する緯度経度のオフセットを計算する
@@ -49,19 +216,38 @@ pub fn haversine_distance(coord1: Coordinate, coord2: Coordinate) -> f64 {
 /// 半径検索のためのBoundingBox作成に使用します。
 /// 経度のオフセットは緯度によって変化するため、緯度でコサイン補正を行います。
 ///
+/// 地球を球とみなし、緯度1度を約111,320m、経度1度を`111320 * cos(緯度)`mとする
+/// 近似です。日本の範囲で数km程度までの距離であれば誤差は1%未満ですが、
+/// 高緯度ほど`cos(緯度)`が小さくなり経度オフセットが急激に大きくなります。
+/// 極付近での発散を避けるため、コサイン補正に使う緯度は日本の範囲
+/// （北緯20〜46度）にクランプします。
+///
 /// # 引数
 /// * `center` - 中心座標
 /// * `radius_meters` - 半径（メートル）
 ///
 /// # 戻り値
 /// (緯度オフセット, 経度オフセット) のタプル（度単位）
-pub(crate) fn calculate_bbox_offsets(center: Coordinate, radius_meters: f64) -> (f64, f64) {
+///
+/// # 例
+///
+/// ```
+/// use jismeshcode::prelude::*;
+///
+/// let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+/// let (lat_offset, lon_offset) = calculate_bbox_offsets(tokyo, 1000.0);
+/// assert!(lon_offset > lat_offset);
+/// ```
+pub fn calculate_bbox_offsets(center: Coordinate, radius_meters: f64) -> (f64, f64) {
     // 緯度1度 ≈ 111,320メートル（ほぼ一定）
     let lat_offset = radius_meters / 111320.0;
 
     // 経度1度の距離は緯度により変わる（極に近いほど短くなる）
-    // cos(緯度)で補正
-    let lon_offset = radius_meters / (111320.0 * math::cos(center.lat().to_radians()));
+    // cos(緯度)で補正（日本の範囲外の緯度はクランプ）
+    let lat = center
+        .lat()
+        .clamp(JAPAN_BOUNDS.min_lat(), JAPAN_BOUNDS.max_lat());
+    let lon_offset = radius_meters / (111320.0 * math::cos(lat.to_radians()));
 
     (lat_offset, lon_offset)
 }
@@ -70,6 +256,76 @@ pub(crate) fn calculate_bbox_offsets(center: Coordinate, radius_meters: f64) ->
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_haversine_distance_with_default_radius() {
+        let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
+        for (lat, lon) in [
+            (35.4437, 139.6380),
+            (43.0687, 141.3508),
+            (35.6812, 139.7671),
+        ] {
+            let other = Coordinate::new(lat, lon).unwrap();
+            assert_eq!(
+                haversine_distance_with_radius(tokyo, other, EARTH_RADIUS_METERS),
+                haversine_distance(tokyo, other)
+            );
+        }
+
+        // 距離は半径に比例する
+        let yokohama = Coordinate::new(35.4437, 139.6380).unwrap();
+        let doubled = haversine_distance_with_radius(tokyo, yokohama, 2.0 * EARTH_RADIUS_METERS);
+        assert!((doubled - 2.0 * haversine_distance(tokyo, yokohama)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_haversine_distance_precomputed_matches() {
+        let fixed = Coordinate::new(35.6812, 139.7671).unwrap();
+        let trig = haversine_precompute(fixed);
+        for (lat, lon) in [
+            (35.4437, 139.6380),
+            (43.0687, 141.3508),
+            (26.2124, 127.6809),
+            (35.6812, 139.7671),
+        ] {
+            let other = Coordinate::new(lat, lon).unwrap();
+            assert_eq!(
+                haversine_distance_precomputed(fixed, trig, other),
+                haversine_distance(fixed, other)
+            );
+        }
+    }
+
+    #[test]
+    fn test_mesh_bearing_cardinal_directions() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let east = MeshCode::from_str("53394614").unwrap();
+        let north = MeshCode::from_str("53394641").unwrap();
+        let west = MeshCode::from_str("53394610").unwrap();
+        let south = MeshCode::from_str("53394601").unwrap();
+
+        assert!((mesh_bearing(mesh, east) - 90.0).abs() < 0.1);
+        assert!(mesh_bearing(mesh, north) < 0.1);
+        assert!((mesh_bearing(mesh, west) - 270.0).abs() < 0.1);
+        assert!((mesh_bearing(mesh, south) - 180.0).abs() < 0.1);
+        assert_eq!(mesh_bearing(mesh, mesh), 0.0);
+    }
+
+    #[test]
+    fn test_calculate_bbox_offsets_high_latitude() {
+        let coord = Coordinate::new(45.0, 141.0).unwrap();
+        let (lat_offset, lon_offset) = calculate_bbox_offsets(coord, 1000.0);
+        assert!(lon_offset > lat_offset);
+        assert!((lon_offset / lat_offset - 2f64.sqrt()).abs() < 1e-9);
+
+        // 日本の範囲外の緯度はクランプされ、極付近でも発散しない
+        let polar = Coordinate::new_unchecked(89.9, 141.0);
+        let edge = Coordinate::new(46.0, 141.0).unwrap();
+        assert_eq!(
+            calculate_bbox_offsets(polar, 1000.0),
+            calculate_bbox_offsets(edge, 1000.0)
+        );
+    }
+
     #[test]
     fn test_haversine_distance_same_point() {
         let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
@@ -98,6 +354,32 @@ mod tests {
         assert!((dist1 - dist2).abs() < 0.01, "距離計算は対称");
     }
 
+    #[test]
+    fn test_distance_to_mesh_edge_inside() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let bounds = mesh_to_bounds(mesh);
+
+        assert_eq!(distance_to_mesh_edge(bounds.center(), mesh), 0.0);
+        assert_eq!(distance_to_mesh_edge(bounds.south_west(), mesh), 0.0);
+    }
+
+    #[test]
+    fn test_distance_to_mesh_edge_one_mesh_east() {
+        let mesh = MeshCode::from_str("53394611").unwrap();
+        let bounds = mesh_to_bounds(mesh);
+        let lon_size = mesh.level().lon_size_degrees();
+
+        let center_lat = bounds.center().lat();
+        let east = Coordinate::new_unchecked(center_lat, bounds.max_lon() + lon_size);
+        let distance = distance_to_mesh_edge(east, mesh);
+
+        // 3次メッシュの東西幅（東京付近で約1.13km）とほぼ等しい
+        let edge = Coordinate::new_unchecked(center_lat, bounds.max_lon());
+        let mesh_width = haversine_distance(edge, east);
+        assert!((distance - mesh_width).abs() < 1.0);
+        assert!(distance > 1000.0 && distance < 1300.0);
+    }
+
     #[test]
     fn test_calculate_bbox_offsets_positive() {
         let tokyo = Coordinate::new(35.6812, 139.7671).unwrap();
@@ -12,6 +12,16 @@ pub(crate) fn floor(x: f64) -> f64 {
     libm::floor(x)
 }
 
+#[cfg(feature = "std")]
+pub(crate) fn ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn ceil(x: f64) -> f64 {
+    libm::ceil(x)
+}
+
 #[cfg(feature = "std")]
 pub(crate) fn sin(x: f64) -> f64 {
     x.sin()
@@ -51,3 +61,63 @@ pub(crate) fn atan2(y: f64, x: f64) -> f64 {
 pub(crate) fn atan2(y: f64, x: f64) -> f64 {
     libm::atan2(y, x)
 }
+
+#[cfg(feature = "std")]
+pub(crate) fn asin(x: f64) -> f64 {
+    x.asin()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn asin(x: f64) -> f64 {
+    libm::asin(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn sinh(x: f64) -> f64 {
+    x.sinh()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sinh(x: f64) -> f64 {
+    libm::sinh(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn cosh(x: f64) -> f64 {
+    x.cosh()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn cosh(x: f64) -> f64 {
+    libm::cosh(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn round(x: f64) -> f64 {
+    x.round()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn round(x: f64) -> f64 {
+    libm::round(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    x.powf(n)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    libm::pow(x, n)
+}
@@ -50,6 +50,47 @@ fn test_japan_range_boundaries() {
     assert!(Coordinate::new(46.1, 154.0).is_err());
 }
 
+#[test]
+fn test_errors_are_non_exhaustive() {
+    // 外部クレートからのmatchにはワイルドカードの分岐が必要
+    let describe_mesh_error = |err: &MeshCodeError| match err {
+        MeshCodeError::OutOfRange => "out of range",
+        MeshCodeError::InvalidFormat(_) => "format",
+        _ => "other",
+    };
+    let describe_coord_error = |err: &CoordinateError| match err {
+        CoordinateError::OutOfJapanRange => "outside japan",
+        _ => "other",
+    };
+
+    let mesh_err = MeshCode::from_str("53a9").unwrap_err();
+    assert_eq!(describe_mesh_error(&mesh_err), "other");
+    assert_eq!(
+        describe_mesh_error(&MeshCode::new(MeshLevel::Third, 533946111).unwrap_err()),
+        "out of range"
+    );
+
+    let coord_err = Coordinate::new(10.0, 139.0).unwrap_err();
+    assert_eq!(describe_coord_error(&coord_err), "outside japan");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_error_source() {
+    use std::error::Error;
+
+    let mesh_err = MeshCode::from_str("53a9").unwrap_err();
+    assert!(mesh_err.source().is_none());
+
+    let coord_err = Coordinate::new(10.0, 139.0).unwrap_err();
+    assert!(coord_err.source().is_none());
+
+    // 他のエラーを包むNeighborErrorは元のエラーをsourceとして返す
+    let neighbor_err = NeighborError::ConversionFailed(MeshCodeError::OutOfRange);
+    let source = neighbor_err.source().unwrap();
+    assert_eq!(source.to_string(), MeshCodeError::OutOfRange.to_string());
+}
+
 // ========================================
 // メッシュコードパースエラーのテスト
 // ========================================
@@ -0,0 +1,58 @@
+//! `MeshCodeStr`のアロケーションのテスト
+//!
+//! グローバルアロケータでヒープ確保回数を数え、`Display`が再整形による
+//! 確保を行わないことを確認します。
+
+use jismeshcode::prelude::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+#[test]
+fn test_mesh_code_str_display_does_not_allocate() {
+    let mesh = MeshCode::from_str("53394611").unwrap();
+    let interned = MeshCodeStr::new(mesh);
+    let mut buf = String::with_capacity(64);
+
+    let before = allocations();
+    for _ in 0..100 {
+        buf.clear();
+        write!(buf, "{interned}").unwrap();
+    }
+    assert_eq!(
+        allocations(),
+        before,
+        "MeshCodeStrのDisplayは確保を行わない"
+    );
+    assert_eq!(buf, "53394611");
+
+    // 比較: MeshCodeのDisplayは呼び出しごとに文字列を生成する
+    let before = allocations();
+    buf.clear();
+    write!(buf, "{mesh}").unwrap();
+    assert!(allocations() > before);
+}
@@ -87,3 +87,25 @@ fn test_direction_roundtrip() {
         assert_eq!(restored, dir);
     }
 }
+
+#[test]
+fn test_mesh_code_numeric_roundtrip() {
+    for code in ["5339", "53394611", "5339461142"] {
+        let mesh = MeshCodeNumeric::from(MeshCode::from_str(code).unwrap());
+        let json = serde_json::to_string(&mesh).unwrap();
+        assert_eq!(json, code);
+        let restored: MeshCodeNumeric = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, mesh);
+    }
+}
+
+#[test]
+fn test_mesh_code_numeric_leading_zero() {
+    // 先頭がゼロのコードは数値として表現できないためシリアライズはエラー
+    let mesh = MeshCodeNumeric::from(MeshCode::from_str("0001").unwrap());
+    assert!(serde_json::to_string(&mesh).is_err());
+
+    // 文字列や不正な桁数の数値は拒否される
+    assert!(serde_json::from_str::<MeshCodeNumeric>("\"53394611\"").is_err());
+    assert!(serde_json::from_str::<MeshCodeNumeric>("12345").is_err());
+}